@@ -0,0 +1,79 @@
+//! Parse and solve timings of every day on the real puzzle inputs
+//!
+//! Run with `cargo bench`, or `cargo bench -- <day>...` for selected days.
+//! Set `AOC_BENCH_RUNS` to change the number of runs per part (default: `runs`
+//! of `aoc.toml`) and `AOC_BENCH_BASELINE` to compare against a file saved by
+//! `aoc bench --save`.
+//! Set `AOC_BENCH_MEMORY` to count the allocations of every part as well.
+
+use advent_of_code_2022::bench::{self, Baseline};
+use advent_of_code_2022::config::{self, Config};
+use advent_of_code_2022::days;
+use advent_of_code_2022::input::InputSource;
+use advent_of_code_2022::memory::CountingAllocator;
+use advent_of_code_2022::solution::Part;
+use std::path::Path;
+use std::process::ExitCode;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    // Cargo passes `--bench` to the harness, only day numbers are of interest
+    let selected: Vec<u8> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+    let runs = std::env::var("AOC_BENCH_RUNS")
+        .ok()
+        .and_then(|runs| runs.parse().ok())
+        .unwrap_or_else(|| config.bench_runs.unwrap_or(bench::DEFAULT_RUNS));
+    // The input directory may be set by the config too
+    config::init(config);
+    let memory = std::env::var_os("AOC_BENCH_MEMORY").is_some();
+    let baseline = match std::env::var("AOC_BENCH_BASELINE") {
+        Ok(path) => Baseline::load(Path::new(&path))?,
+        Err(_) => Baseline::default(),
+    };
+
+    let mut timings = vec![];
+    for day in days::DAYS {
+        if !selected.is_empty() && !selected.contains(&day.day) {
+            continue;
+        }
+        let source = InputSource::for_day(day.day);
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("skipping day {}: {}", day.day, e);
+                continue;
+            }
+        };
+        let result = bench::bench(day, &input, &Part::ALL, runs).and_then(|mut timings| {
+            if memory {
+                bench::measure_memory(day, &input, &mut timings)?;
+            }
+            Ok(timings)
+        });
+        match result {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(e) => eprintln!("{}", e.with_file(&source.to_string()).diagnostic(&input)),
+        }
+    }
+
+    for timing in &timings {
+        println!("{}", timing.describe_against(&baseline));
+    }
+    Ok(())
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code_2022::answers::Answers;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Answers::parse(input);
+});
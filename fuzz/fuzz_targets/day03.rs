@@ -0,0 +1,9 @@
+#![no_main]
+
+use advent_of_code_2022::days::day03::Day03;
+use advent_of_code_2022::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day03::parse(input);
+});
@@ -0,0 +1,20 @@
+#!/bin/sh
+# Seed the corpus of every target with the puzzle inputs and examples, and
+# the regression inputs under seeds/
+set -eu
+cd "$(dirname "$0")"
+
+for target in fuzz_targets/day*.rs; do
+    target=$(basename "$target" .rs)
+    day=$(echo "$target" | sed 's/^day0*//')
+    mkdir -p "corpus/$target"
+    for file in ../input/day"$day".txt ../input/examples/day"$day".txt ../input/examples/day"$day"-*.txt seeds/day"$day"-*.txt; do
+        if [ -f "$file" ]; then
+            # Inputs and examples share file names
+            cp "$file" "corpus/$target/$(basename "$(dirname "$file")")-$(basename "$file")"
+        fi
+    done
+done
+
+mkdir -p corpus/answers
+cp ../answers.toml corpus/answers/
@@ -0,0 +1,218 @@
+//! Registry of known-good answers checked by `aoc verify`
+//!
+//! The answers are stored in `answers.toml` as an array of tables, one per
+//! day and input file. The input is identified by the hash of its contents,
+//! so answers for example inputs can live next to the ones for the real input:
+//!
+//! ```toml
+//! [[answer]]
+//! day = 10
+//! input = "5f2b6c3a9d1e8f07"
+//! part1 = 14160
+//! part2 = "###    ## ...\n"
+//! ```
+//!
+//! Only the subset of TOML needed for this file is supported: comments,
+//! `[[answer]]` headers, integers and single line basic strings.
+
+use crate::error::ParseError;
+use crate::parse::{lines, Line};
+use crate::solution::{Answer, Part};
+use crate::toml::{integer, key_value, quote, string};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Default name of the answers file
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Location of the answers file
+///
+/// `answers.toml` is looked up from the working directory first and then
+/// from the directory of this crate.
+pub fn answers_path() -> PathBuf {
+    let local = PathBuf::from(ANSWERS_FILE);
+    if local.is_file() {
+        return local;
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE)
+}
+
+/// Hash of the input file contents (64-bit FNV-1a as hex)
+///
+/// Carriage returns are skipped so that the hash does not depend on the line
+/// endings of the checkout.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in input.bytes().filter(|b| *b != b'\r') {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Known answers of one day for one input
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Entry {
+    fn part(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Outcome of checking an answer against the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+/// Contents of the answers file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<Entry>,
+}
+
+impl Answers {
+    /// Known answer for given day, input hash and part
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.input == input)
+            .and_then(|e| e.part(part))
+    }
+
+    /// Compare an answer against the known one
+    pub fn check(&self, day: u8, input: &str, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, input, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+
+    /// Record an answer, replacing the known one
+    pub fn insert(&mut self, day: u8, input: &str, part: Part, answer: Answer) {
+        let index = match self
+            .entries
+            .iter()
+            .position(|e| e.day == day && e.input == input)
+        {
+            Some(index) => index,
+            None => {
+                self.entries.push(Entry {
+                    day,
+                    input: input.to_string(),
+                    ..Entry::default()
+                });
+                self.entries.len() - 1
+            }
+        };
+        let entry = &mut self.entries[index];
+        match part {
+            Part::One => entry.part1 = Some(answer),
+            Part::Two => entry.part2 = Some(answer),
+        }
+    }
+
+    /// All entries in the order of the file
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Parse the contents of an answers file
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        let mut current: Option<(Line, Entry)> = None;
+
+        for line in lines(input) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            if text == "[[answer]]" {
+                if let Some((header, entry)) = current.take() {
+                    answers.entries.push(finish_entry(header, entry)?);
+                }
+                current = Some((line, Entry::default()));
+                continue;
+            }
+            if text.starts_with('[') {
+                return Err(line.error(text, "`[[answer]]`"));
+            }
+
+            let Some((_, entry)) = current.as_mut() else {
+                return Err(line.error(text, "`[[answer]]` before the first key"));
+            };
+            let (key, value) = key_value(&line)?;
+            match key {
+                "day" => entry.day = integer(&line, value, "day")?,
+                "input" => entry.input = string(&line, value)?,
+                "part1" => entry.part1 = Some(answer(&line, value)?),
+                "part2" => entry.part2 = Some(answer(&line, value)?),
+                _ => {
+                    return Err(line.error(key, "`day`, `input`, `part1` or `part2`"));
+                }
+            }
+        }
+        if let Some((header, entry)) = current.take() {
+            answers.entries.push(finish_entry(header, entry)?);
+        }
+        Ok(answers)
+    }
+}
+
+/// Check that the required keys of an entry were given
+fn finish_entry(header: Line, entry: Entry) -> Result<Entry, ParseError> {
+    if entry.day == 0 {
+        return Err(header.error_at_end("`day` key in the table"));
+    }
+    if entry.input.is_empty() {
+        return Err(header.error_at_end("`input` key in the table"));
+    }
+    Ok(entry)
+}
+
+fn answer(line: &Line, value: &str) -> Result<Answer, ParseError> {
+    if value.starts_with('"') {
+        Ok(Answer::Text(string(line, value)?))
+    } else {
+        Ok(Answer::Number(integer(line, value, "number or string")?))
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Known-good answers checked by `aoc verify`")?;
+        writeln!(f, "#")?;
+        writeln!(f, "# Each entry holds the answers of one day for the input")?;
+        writeln!(f, "# whose contents hash to `input`.")?;
+
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by_key(|e| e.day);
+        for entry in entries {
+            writeln!(f, "\n[[answer]]")?;
+            writeln!(f, "day = {}", entry.day)?;
+            writeln!(f, "input = {}", quote(&entry.input))?;
+            for (key, answer) in [("part1", &entry.part1), ("part2", &entry.part2)] {
+                match answer {
+                    Some(Answer::Number(n)) => writeln!(f, "{} = {}", key, n)?,
+                    Some(Answer::Text(s)) => writeln!(f, "{} = {}", key, quote(s))?,
+                    None => (),
+                }
+            }
+        }
+        Ok(())
+    }
+}
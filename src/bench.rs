@@ -0,0 +1,313 @@
+//! Timing of the parse and solve phases over repeated runs
+
+use crate::days::Day;
+use crate::error::{Error, ParseError};
+use crate::input::read_input_file;
+use crate::memory::{self, Usage};
+use crate::parse::lines;
+use crate::solution::Part;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+/// Number of runs per part benchmarked by default
+pub const DEFAULT_RUNS: usize = 10;
+
+/// Minimum, median and maximum of a set of samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Statistics of the samples or `None` if there are none
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        samples.sort();
+        let median = match samples.len() {
+            0 => return None,
+            n if n % 2 == 0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            n => samples[n / 2],
+        };
+        Some(Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} / {}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.max)
+        )
+    }
+}
+
+/// Timings of a single part
+#[derive(Debug, Clone)]
+pub struct Timing {
+    pub day: u8,
+    pub part: Part,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Heap usage of parsing and solving, see [`measure_memory`]
+    pub memory: Option<Usage>,
+}
+
+impl Timing {
+    /// Change of the median total time relative to the baseline
+    pub fn compare(&self, baseline: &Baseline) -> Option<f64> {
+        let (parse, solve) = baseline.get(self.day, self.part)?;
+        Some(change_percent(
+            parse + solve,
+            self.parse.median + self.solve.median,
+        ))
+    }
+
+    /// Change of the allocation count and peak heap usage relative to the
+    /// baseline
+    pub fn compare_memory(&self, baseline: &Baseline) -> Option<(f64, f64)> {
+        let current = self.memory?;
+        let saved = baseline.memory(self.day, self.part)?;
+        let change = |saved: u64, current: u64| match saved {
+            0 => 0.0,
+            _ => (current as f64 - saved as f64) / saved as f64 * 100.0,
+        };
+        Some((
+            change(saved.allocations, current.allocations),
+            change(saved.peak, current.peak),
+        ))
+    }
+
+    /// Timing followed by its changes relative to the baseline, if any
+    pub fn describe_against(&self, baseline: &Baseline) -> String {
+        let mut changes = vec![];
+        if let Some(change) = self.compare(baseline) {
+            changes.push(format!("{:+.1}%", change));
+        }
+        if let Some((allocations, peak)) = self.compare_memory(baseline) {
+            changes.push(format!("allocs {:+.1}%, peak {:+.1}%", allocations, peak));
+        }
+        match changes.is_empty() {
+            true => self.to_string(),
+            false => format!("{} ({} vs baseline)", self, changes.join(", ")),
+        }
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {}: parse {}, solve {}",
+            self.day, self.part, self.parse, self.solve
+        )?;
+        if let Some(memory) = &self.memory {
+            write!(f, ", {}", memory)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the given parts of a day `runs` times and collect the timings
+pub fn bench(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<Timing>, ParseError> {
+    let mut samples = HashMap::<Part, (Vec<Duration>, Vec<Duration>)>::new();
+    for _ in 0..runs.max(1) {
+        // Each part is run separately so that the parse time is measured for
+        // every part and not shared between them
+        for &part in parts {
+            for report in (day.run)(input, &[part])? {
+                let entry = samples.entry(report.part).or_default();
+                entry.0.push(report.parse_time);
+                entry.1.push(report.solve_time);
+            }
+        }
+    }
+
+    let mut timings = vec![];
+    for part in parts {
+        if let Some((parse, solve)) = samples.get_mut(part) {
+            timings.push(Timing {
+                day: day.day,
+                part: *part,
+                parse: Stats::from_samples(parse).unwrap(),
+                solve: Stats::from_samples(solve).unwrap(),
+                memory: None,
+            });
+        }
+    }
+    Ok(timings)
+}
+
+/// Parse and solve each timed part once more and count its allocations
+///
+/// The usage covers the whole run of the part, parsing included. Nothing is
+/// counted unless [`memory::CountingAllocator`] is the global allocator.
+pub fn measure_memory(day: &Day, input: &str, timings: &mut [Timing]) -> Result<(), ParseError> {
+    for timing in timings {
+        let (result, usage) = memory::measure(|| (day.run)(input, &[timing.part]));
+        result?;
+        timing.memory = Some(usage);
+    }
+    Ok(())
+}
+
+/// Saved measurements of one part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    parse: Duration,
+    solve: Duration,
+    memory: Option<Usage>,
+}
+
+/// Median timings saved from an earlier benchmark run
+///
+/// Stored as text with one `<day> <part> <parse ns> <solve ns>` line per
+/// part, followed by `<allocations> <bytes> <peak bytes>` when the memory
+/// usage was measured. Lines starting with `#` are comments.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    entries: HashMap<(u8, Part), Entry>,
+}
+
+impl Baseline {
+    /// Baseline of the median timings
+    pub fn from_timings(timings: &[Timing]) -> Self {
+        let mut baseline = Baseline::default();
+        baseline.update(timings);
+        baseline
+    }
+
+    /// Replace the entries of the timed parts
+    pub fn update(&mut self, timings: &[Timing]) {
+        for t in timings {
+            let entry = Entry {
+                parse: t.parse.median,
+                solve: t.solve.median,
+                memory: t.memory,
+            };
+            self.entries.insert((t.day, t.part), entry);
+        }
+    }
+
+    /// Median parse and solve time of given part
+    pub fn get(&self, day: u8, part: Part) -> Option<(Duration, Duration)> {
+        let entry = self.entries.get(&(day, part))?;
+        Some((entry.parse, entry.solve))
+    }
+
+    /// Heap usage of given part if it was measured
+    pub fn memory(&self, day: u8, part: Part) -> Option<Usage> {
+        self.entries.get(&(day, part))?.memory
+    }
+
+    /// Read a baseline file saved by `aoc bench --save`
+    ///
+    /// Parse errors are returned as a diagnostic showing the offending line.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let name = path.display().to_string();
+        let data = read_input_file(path).map_err(|e| Error::io(&name, e).to_string())?;
+        Baseline::parse(&data).map_err(|e| e.with_file(&name).diagnostic(&data))
+    }
+
+    /// Parse a baseline file
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut baseline = Baseline::default();
+        for line in lines(input) {
+            if line.text.trim().is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+            let day = line.number(line.word(0, "day")?, "day")?;
+            let part = line.word(1, "part")?;
+            let part = part
+                .parse::<Part>()
+                .map_err(|_| line.error(part, "part `1` or `2`"))?;
+            let parse = line.number(line.word(2, "parse time")?, "parse time")?;
+            let solve = line.number(line.word(3, "solve time")?, "solve time")?;
+            let memory = match line.words().len() {
+                4 => None,
+                _ => {
+                    let field = |i, name| line.number(line.word(i, name)?, name);
+                    let usage = Usage {
+                        allocations: field(4, "allocation count")?,
+                        bytes: field(5, "allocated bytes")?,
+                        peak: field(6, "peak bytes")?,
+                    };
+                    line.expect_words(7)?;
+                    Some(usage)
+                }
+            };
+            let entry = Entry {
+                parse: Duration::from_nanos(parse),
+                solve: Duration::from_nanos(solve),
+                memory,
+            };
+            baseline.entries.insert((day, part), entry);
+        }
+        Ok(baseline)
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "# day part parse_ns solve_ns")?;
+        if self.entries.values().any(|e| e.memory.is_some()) {
+            write!(f, " [allocations bytes peak_bytes]")?;
+        }
+        writeln!(f)?;
+        let mut keys: Vec<_> = self.entries.keys().collect();
+        keys.sort();
+        for key in keys {
+            let entry = &self.entries[key];
+            write!(
+                f,
+                "{} {} {} {}",
+                key.0,
+                key.1,
+                entry.parse.as_nanos(),
+                entry.solve.as_nanos()
+            )?;
+            if let Some(m) = entry.memory {
+                write!(f, " {} {} {}", m.allocations, m.bytes, m.peak)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change from `baseline` to `current` in percent
+pub fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+/// Human readable duration with a unit matching its magnitude
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
@@ -0,0 +1,578 @@
+//! Command line argument parsing for the `aoc` runner
+
+use advent_of_code_2022::bench::DEFAULT_RUNS;
+use advent_of_code_2022::config::Config;
+use advent_of_code_2022::days::day02::{HandTable, Rules};
+use advent_of_code_2022::input::InputSource;
+use advent_of_code_2022::log::{Filter, Level};
+use advent_of_code_2022::pool;
+use advent_of_code_2022::solution::Part;
+use advent_of_code_2022::stats::{ReportFormat, ReportOptions};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub const USAGE: &str = "\
+Usage:
+  aoc run <day> [--part <1|2>] [--input <path|->] [--format <text|json>]
+          [--memory]
+  aoc run --all [--part <1|2>] [--format <text|json>] [--jobs <n>]
+  aoc bench <day>|--all [--part <1|2>] [--input <path|->] [--runs <n>]
+            [--save <file>] [--baseline <file>] [--memory]
+  aoc verify [<day>|--all] [--input <path|->] [--answers <file>] [--record]
+  aoc watch <day> [--input <path>]
+  aoc repl <day> [--input <path>]
+  aoc report <day> [--input <path|->] [--csv] [--buckets <n>]
+             [--table <X=hand,...>]
+  aoc fetch <day>|--all
+  aoc submit <day> <1|2> [--answer <answer>] [--input <path|->]
+  aoc new <day> [--title <title>]
+  aoc list
+
+`--jobs` runs the days on a pool of n threads (0: one per CPU) and prints a
+summary table. The inputs are read before and each day is parsed once for
+both parts. `--memory` counts the heap allocations of every part: their
+number, the bytes allocated and the peak heap usage.
+
+Defaults of the input directory, output format, log filter, benchmark runs
+and server URL are read from `aoc.toml` in the working directory or one of
+its parents and from `aoc/aoc.toml` in the user config directory.
+Environment variables and options take precedence over the file.
+
+Options for every command:
+  -v, -vv          show debug or trace output of the solvers on stderr
+                   (day 9 animates its rope at trace level when stderr is a
+                   terminal, which takes minutes for a whole puzzle input)
+  -q, --quiet      hide all solver output
+  --log <filter>   per-day log levels, e.g. `day05=trace,day13=debug`
+                   (default: AOC_LOG)
+
+Input is read from `--input` (`-` for stdin) or from `dayN.txt` or
+`2022/dayN.txt` in the directory given by AOC_INPUT_DIR (default: `input`).
+Missing inputs are fetched from AOC_BASE_URL (default:
+https://adventofcode.com) with the session token in AOC_SESSION.
+
+`report` prints statistics of the parsed input of days that have one (day 1:
+per elf item counts and totals, percentiles and a histogram of the totals;
+day 2: the score of the strategy guide for every reading of X, Y and Z as
+hands, or of the reading given with `--table`, like
+`X=rock,Y=paper,Z=scissors`). `--csv` prints the main table as CSV instead.
+
+`repl` reads commands for exploring the parsed input from stdin, `help` lists
+the commands of the day.
+
+`submit` posts the computed answer, or the one given with `--answer`, to the
+same server. Attempts are recorded in `2022/submissions.log` under the input
+directory; known wrong answers are not submitted again.";
+
+/// Which days to run
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+/// Output format of the answers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable lines
+    #[default]
+    Text,
+    /// One JSON object per answer or error
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format `{}`", s)),
+        }
+    }
+}
+
+/// Parsed subcommand
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        part: Option<Part>,
+        input: Option<InputSource>,
+        format: Format,
+        jobs: Option<usize>,
+        memory: bool,
+    },
+    Bench {
+        selection: Selection,
+        part: Option<Part>,
+        input: Option<InputSource>,
+        runs: usize,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        memory: bool,
+    },
+    Verify {
+        selection: Selection,
+        input: Option<InputSource>,
+        answers: Option<PathBuf>,
+        record: bool,
+    },
+    Watch {
+        day: u8,
+        input: Option<InputSource>,
+    },
+    Repl {
+        day: u8,
+        input: Option<InputSource>,
+    },
+    Report {
+        day: u8,
+        input: Option<InputSource>,
+        options: ReportOptions,
+        /// Reading of the columns of day 2 to score
+        table: Option<HandTable>,
+    },
+    Fetch {
+        selection: Selection,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+        input: Option<InputSource>,
+    },
+    New {
+        day: u8,
+        title: Option<String>,
+    },
+    List,
+}
+
+/// Options of the commands that take a value, which is never a logging option
+const VALUE_OPTIONS: &[&str] = &[
+    "--part",
+    "-p",
+    "--input",
+    "-i",
+    "--format",
+    "-f",
+    "--jobs",
+    "-j",
+    "--runs",
+    "-n",
+    "--save",
+    "--baseline",
+    "--answers",
+    "--buckets",
+    "-b",
+    "--table",
+    "--title",
+    "-t",
+    "--answer",
+    "-a",
+];
+
+/// Remove the logging options from the arguments and build the log filter
+///
+/// `env` is the value of the `AOC_LOG` variable, used as the base filter.
+/// `-v` and `-vv` raise the default level to debug and trace, `--quiet`
+/// turns it off. Directives given with `--log` are added on top. The value of
+/// another option is passed on as is, even when it looks like `-v`.
+pub fn split_log_args<I>(args: I, env: Option<&str>) -> Result<(Vec<String>, Filter), String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut rest = vec![];
+    let mut verbosity = 0;
+    let mut quiet = false;
+    let mut specs = vec![];
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "-q" | "--quiet" => quiet = true,
+            "--log" => specs.push(args.next().ok_or("missing value for --log")?),
+            _ if VALUE_OPTIONS.contains(&arg.as_str()) => {
+                rest.push(arg);
+                rest.extend(args.next());
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    let mut filter = Filter::parse(env.unwrap_or(""), Level::Warn)?;
+    filter.default = match (quiet, verbosity) {
+        (true, _) => Level::Off,
+        (false, 0) => filter.default,
+        (false, 1) => filter.default.max(Level::Debug),
+        (false, _) => Level::Trace,
+    };
+    for spec in specs {
+        filter.add(&spec)?;
+    }
+    Ok((rest, filter))
+}
+
+/// Parse the command line arguments (without the program name)
+///
+/// Options that are not given default to the ones of `config`.
+pub fn parse_args<I>(args: I, config: &Config) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => parse_run(args, config),
+        Some("bench") => parse_bench(args, config),
+        Some("verify") => parse_verify(args),
+        Some("watch") => parse_watch(args),
+        Some("repl") => parse_repl(args),
+        Some("report") => parse_report(args),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some("new") => parse_new(args),
+        Some("list") => Ok(Command::List),
+        Some(other) => Err(format!("unknown command `{}`", other)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+/// Day selection, part and input shared by `run` and `bench`
+#[derive(Default)]
+struct Target {
+    selection: Option<Selection>,
+    part: Option<Part>,
+    input: Option<InputSource>,
+}
+
+impl Target {
+    /// Consume a target argument and its value
+    fn accept<I>(&mut self, arg: &str, args: &mut I) -> Result<(), String>
+    where
+        I: Iterator<Item = String>,
+    {
+        match arg {
+            "--all" => self.selection = Some(Selection::All),
+            "--part" | "-p" => {
+                let value = args.next().ok_or("missing value for --part")?;
+                self.part = Some(value.parse::<Part>()?);
+            }
+            "--input" | "-i" => {
+                let value = args.next().ok_or("missing value for --input")?;
+                self.input = Some(InputSource::from_arg(&value));
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => {
+                let day = arg
+                    .parse::<u8>()
+                    .map_err(|_| format!("invalid day `{}`", arg))?;
+                self.selection = Some(Selection::Day(day));
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(Selection, Option<Part>, Option<InputSource>), String> {
+        let selection = self.selection.ok_or("expected a day or --all")?;
+        if selection == Selection::All && self.input.is_some() {
+            return Err("--input cannot be used together with --all".to_string());
+        }
+        Ok((selection, self.part, self.input))
+    }
+}
+
+fn parse_run<I>(mut args: I, config: &Config) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    let mut format = match &config.format {
+        Some(format) => format.parse()?,
+        None => Format::default(),
+    };
+    let mut jobs = None;
+    let mut memory = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                let value = args.next().ok_or("missing value for --format")?;
+                format = value.parse()?;
+            }
+            "--jobs" | "-j" => {
+                let value = args.next().ok_or("missing value for --jobs")?;
+                jobs = Some(match value.parse::<usize>() {
+                    Ok(0) => pool::default_threads(),
+                    Ok(n) => n,
+                    Err(_) => return Err(format!("invalid job count `{}`", value)),
+                });
+            }
+            "--memory" => memory = true,
+            _ => target.accept(&arg, &mut args)?,
+        }
+    }
+
+    let (selection, part, input) = target.finish()?;
+    if memory && jobs.is_some() {
+        return Err("--memory cannot be used together with --jobs".to_string());
+    }
+    Ok(Command::Run {
+        selection,
+        part,
+        input,
+        format,
+        jobs,
+        memory,
+    })
+}
+
+fn parse_bench<I>(mut args: I, config: &Config) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    let mut runs = config.bench_runs.unwrap_or(DEFAULT_RUNS);
+    let mut save = None;
+    let mut baseline = None;
+    let mut memory = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--memory" => memory = true,
+            "--runs" | "-n" => {
+                let value = args.next().ok_or("missing value for --runs")?;
+                runs = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid run count `{}`", value)),
+                };
+            }
+            "--save" => {
+                save = Some(PathBuf::from(
+                    args.next().ok_or("missing value for --save")?,
+                ));
+            }
+            "--baseline" => {
+                baseline = Some(PathBuf::from(
+                    args.next().ok_or("missing value for --baseline")?,
+                ));
+            }
+            _ => target.accept(&arg, &mut args)?,
+        }
+    }
+
+    let (selection, part, input) = target.finish()?;
+    Ok(Command::Bench {
+        selection,
+        part,
+        input,
+        runs,
+        save,
+        baseline,
+        memory,
+    })
+}
+
+fn parse_verify<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    let mut answers = None;
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers = Some(PathBuf::from(
+                    args.next().ok_or("missing value for --answers")?,
+                ));
+            }
+            "--record" => record = true,
+            _ => target.accept(&arg, &mut args)?,
+        }
+    }
+
+    // Every day is verified unless one is given
+    target.selection.get_or_insert(Selection::All);
+    let (selection, part, input) = target.finish()?;
+    if part.is_some() {
+        return Err("--part cannot be used with verify".to_string());
+    }
+    Ok(Command::Verify {
+        selection,
+        input,
+        answers,
+        record,
+    })
+}
+
+fn parse_new<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut title = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" | "-t" => title = Some(args.next().ok_or("missing value for --title")?),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => {
+                let value = arg
+                    .parse::<u8>()
+                    .map_err(|_| format!("invalid day `{}`", arg))?;
+                day = Some(value);
+            }
+        }
+    }
+
+    let day = day.ok_or("expected a day")?;
+    Ok(Command::New { day, title })
+}
+
+fn parse_watch<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    while let Some(arg) = args.next() {
+        target.accept(&arg, &mut args)?;
+    }
+
+    let (selection, part, input) = target.finish()?;
+    let Selection::Day(day) = selection else {
+        return Err("watch takes a single day".to_string());
+    };
+    if part.is_some() {
+        return Err("--part cannot be used with watch".to_string());
+    }
+    if input == Some(InputSource::Stdin) {
+        return Err("stdin cannot be watched, give a file with --input".to_string());
+    }
+    Ok(Command::Watch { day, input })
+}
+
+fn parse_repl<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    while let Some(arg) = args.next() {
+        target.accept(&arg, &mut args)?;
+    }
+
+    let (selection, part, input) = target.finish()?;
+    let Selection::Day(day) = selection else {
+        return Err("repl takes a single day".to_string());
+    };
+    if part.is_some() {
+        return Err("--part cannot be used with repl".to_string());
+    }
+    if input == Some(InputSource::Stdin) {
+        return Err("stdin is read for commands, give a file with --input".to_string());
+    }
+    Ok(Command::Repl { day, input })
+}
+
+fn parse_report<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    let mut options = ReportOptions::default();
+    let mut table = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--csv" => options.format = ReportFormat::Csv,
+            "--buckets" | "-b" => {
+                let value = args.next().ok_or("missing value for --buckets")?;
+                options.buckets = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid bucket count `{}`", value)),
+                };
+            }
+            "--table" | "-t" => {
+                let value = args.next().ok_or("missing value for --table")?;
+                let rules = Rules::rock_paper_scissors();
+                table = Some(HandTable::parse(&rules, &value)?);
+            }
+            _ => target.accept(&arg, &mut args)?,
+        }
+    }
+
+    let (selection, part, input) = target.finish()?;
+    let Selection::Day(day) = selection else {
+        return Err("report takes a single day".to_string());
+    };
+    if part.is_some() {
+        return Err("--part cannot be used with report".to_string());
+    }
+    if table.is_some() && day != 2 {
+        return Err("--table is only used by the report of day 2".to_string());
+    }
+    Ok(Command::Report {
+        day,
+        input,
+        options,
+        table,
+    })
+}
+
+fn parse_fetch<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut target = Target::default();
+    while let Some(arg) = args.next() {
+        target.accept(&arg, &mut args)?;
+    }
+
+    let (selection, part, input) = target.finish()?;
+    if part.is_some() || input.is_some() {
+        return Err("fetch only takes a day or --all".to_string());
+    }
+    Ok(Command::Fetch { selection })
+}
+
+fn parse_submit<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut positional = vec![];
+    let mut answer = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answer" | "-a" => answer = Some(args.next().ok_or("missing value for --answer")?),
+            "--input" | "-i" => {
+                let value = args.next().ok_or("missing value for --input")?;
+                input = Some(InputSource::from_arg(&value));
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{}`", arg))
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let [day, part] = positional.as_slice() else {
+        return Err("expected a day and a part".to_string());
+    };
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| format!("invalid day `{}`", day))?;
+    let part = part.parse::<Part>()?;
+    if answer.is_some() && input.is_some() {
+        return Err("--answer cannot be used together with --input".to_string());
+    }
+    Ok(Command::Submit {
+        day,
+        part,
+        answer,
+        input,
+    })
+}
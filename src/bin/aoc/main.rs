@@ -0,0 +1,715 @@
+//! Unified runner for all Advent of Code 2022 solutions
+
+mod args;
+
+use advent_of_code_2022::answers::{self, Answers, Verdict};
+use advent_of_code_2022::bench::{self, Baseline};
+use advent_of_code_2022::config::{self, Config};
+use advent_of_code_2022::days::day02::{self, HandTable};
+use advent_of_code_2022::days::{self, Day};
+use advent_of_code_2022::error::{Error, ParseError};
+use advent_of_code_2022::fetch::{HttpFetcher, InputCache, InputProvider, SESSION_ENV, YEAR};
+use advent_of_code_2022::input::{input_dir, read_input_file, InputSource};
+use advent_of_code_2022::json;
+use advent_of_code_2022::log::{self, LOG_ENV};
+use advent_of_code_2022::memory::{self, CountingAllocator, Usage};
+use advent_of_code_2022::pool;
+use advent_of_code_2022::repl::{Reply, Session};
+use advent_of_code_2022::scaffold;
+use advent_of_code_2022::solution::{Part, Report};
+use advent_of_code_2022::stats::{self, ReportOptions};
+use advent_of_code_2022::submit::{self, Outcome, SubmissionLog};
+use advent_of_code_2022::table;
+use advent_of_code_2022::watch::{self, Change, Watcher};
+use args::{Command, Format, Selection};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode, Stdio};
+use std::time::Duration;
+
+/// Counts allocations for `--memory`, idle otherwise
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Interval between checks of the watched files
+const WATCH_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Read the input of a day from the given source or the default location
+fn read_input(day: &Day, input: Option<&InputSource>) -> Result<(InputSource, String), String> {
+    let source = day_source(day, input).map_err(|e| e.to_string())?;
+    let data = source.read().map_err(|e| e.to_string())?;
+    Ok((source, data))
+}
+
+/// The given source, or the cached input of the day fetched if missing
+fn day_source(day: &Day, input: Option<&InputSource>) -> Result<InputSource, Error> {
+    match input {
+        Some(source) => Ok(source.clone()),
+        None => InputProvider::from_env()
+            .locate(YEAR, day.day)
+            .map(InputSource::File),
+    }
+}
+
+/// Parts to solve when `part` was or was not given on the command line
+fn selected_parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// Days to run for the selection
+fn selected_days(selection: &Selection) -> Result<Vec<&'static Day>, String> {
+    match selection {
+        Selection::All => Ok(days::DAYS.iter().collect()),
+        Selection::Day(day) => match days::get(*day) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("day {} is not solved", day)),
+        },
+    }
+}
+
+/// Run the selected parts of a single day and print the answers
+fn run_day(
+    day: &Day,
+    part: Option<Part>,
+    input: Option<&InputSource>,
+    format: Format,
+    memory: bool,
+) -> Result<(), String> {
+    let (source, data) = read_input(day, input).inspect_err(|e| {
+        if format == Format::Json {
+            let error = json::Object::new().string("message", e);
+            println!(
+                "{}",
+                json::Object::new()
+                    .value("day", day.day)
+                    .raw("error", error)
+            );
+        }
+    })?;
+    let reports = solve_parts(day, &data, &selected_parts(part), memory).map_err(|e| {
+        let e = e.with_file(&source.to_string());
+        if format == Format::Json {
+            println!(
+                "{}",
+                json::Object::new()
+                    .value("day", day.day)
+                    .raw("error", e.to_json())
+            );
+        }
+        e.diagnostic(&data)
+    })?;
+    for (report, usage) in reports {
+        let answer = report.answer.to_string();
+        if format == Format::Json {
+            let object = report.to_json();
+            match usage {
+                Some(usage) => println!("{}", object.raw("memory", usage.to_json())),
+                None => println!("{}", object),
+            }
+            continue;
+        }
+        if answer.contains('\n') {
+            println!(
+                "Day {} part {}:\n{}",
+                report.day,
+                report.part,
+                answer.trim_end_matches('\n')
+            );
+        } else {
+            println!("Day {} part {}: {}", report.day, report.part, answer);
+        }
+        if let Some(usage) = usage {
+            println!("  memory: {}", usage);
+        }
+    }
+    Ok(())
+}
+
+/// Solve the parts, each on its own with its heap usage when `memory` is set
+fn solve_parts(
+    day: &Day,
+    data: &str,
+    parts: &[Part],
+    memory: bool,
+) -> Result<Vec<(Report, Option<Usage>)>, ParseError> {
+    if !memory {
+        let reports = (day.run)(data, parts)?;
+        return Ok(reports.into_iter().map(|report| (report, None)).collect());
+    }
+    let mut reports = vec![];
+    for &part in parts {
+        let (result, usage) = memory::measure(|| (day.run)(data, &[part]));
+        reports.extend(result?.into_iter().map(|report| (report, Some(usage))));
+    }
+    Ok(reports)
+}
+
+/// Run every selected part on a pool of `jobs` threads and print a summary
+fn run_parallel(
+    days: &[&Day],
+    part: Option<Part>,
+    input: Option<&InputSource>,
+    format: Format,
+    jobs: usize,
+) -> Result<(), String> {
+    // Inputs are located, fetched and read once per day before fanning out
+    let inputs: Vec<_> = days.iter().map(|day| read_input(day, input)).collect();
+    let parts = selected_parts(part);
+    // Each day is parsed once and the parts share the parsed input
+    let results = pool::map(
+        days.iter().zip(inputs).collect(),
+        jobs,
+        |(day, input)| -> Result<Vec<Report>, String> {
+            let (source, data) = input?;
+            (day.run)(&data, &parts).map_err(|e| e.with_file(&source.to_string()).diagnostic(&data))
+        },
+    );
+    let tasks = days.iter().zip(results).flat_map(|(day, result)| {
+        let result = result
+            .map_err(|panic| format!("panicked: {}", panic))
+            .and_then(|result| result);
+        parts.iter().map(move |&part| {
+            let report = match &result {
+                Ok(reports) => reports
+                    .iter()
+                    .find(|report| report.part == part)
+                    .cloned()
+                    .ok_or_else(|| "no answer".to_string()),
+                Err(e) => Err(e.clone()),
+            };
+            (day, part, report)
+        })
+    });
+
+    let mut rows = vec![];
+    let mut details = vec![];
+    let mut failed = 0;
+    for (day, part, result) in tasks {
+        let name = format!("Day {} part {}", day.day, part);
+        if format == Format::Json {
+            match &result {
+                Ok(report) => println!("{}", report.to_json()),
+                Err(e) => {
+                    let error = json::Object::new().string("message", e);
+                    println!(
+                        "{}",
+                        json::Object::new()
+                            .value("day", day.day)
+                            .value("part", part)
+                            .raw("error", error)
+                    );
+                }
+            }
+        }
+        let (answer, time, status) = match result {
+            Ok(report) => {
+                let mut answer = report.answer.to_string();
+                if answer.contains('\n') {
+                    details.push(format!("{}:\n{}", name, answer.trim_end_matches('\n')));
+                    answer = "(see below)".to_string();
+                }
+                let time = bench::format_duration(report.parse_time + report.solve_time);
+                (answer, time, "ok")
+            }
+            Err(e) => {
+                failed += 1;
+                let status = if e.starts_with("panicked") {
+                    "panicked"
+                } else {
+                    "error"
+                };
+                details.push(format!("{}: {}", name, e.trim_end()));
+                (String::new(), String::new(), status)
+            }
+        };
+        rows.push(vec![
+            day.day.to_string(),
+            part.to_string(),
+            answer,
+            time,
+            status.to_string(),
+        ]);
+    }
+
+    if format == Format::Text {
+        print!(
+            "{}",
+            table::render(&["Day", "Part", "Answer", "Time", "Status"], &rows)
+        );
+        for detail in details {
+            println!("\n{}", detail);
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} of {} parts failed", failed, rows.len())),
+    }
+}
+
+/// Benchmark the selected days and print the timings
+fn bench_days(
+    days: &[&Day],
+    part: Option<Part>,
+    input: Option<&InputSource>,
+    runs: usize,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+    memory: bool,
+) -> Result<(), String> {
+    // Nothing is compared against an empty baseline
+    let baseline = baseline
+        .map(Baseline::load)
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut timings = vec![];
+    for day in days {
+        let (source, data) = read_input(day, input)?;
+        let day_timings = bench::bench(day, &data, &selected_parts(part), runs)
+            .and_then(|mut timings| {
+                if memory {
+                    bench::measure_memory(day, &data, &mut timings)?;
+                }
+                Ok(timings)
+            })
+            .map_err(|e| e.with_file(&source.to_string()).diagnostic(&data))?;
+        for timing in &day_timings {
+            println!("{}", timing.describe_against(&baseline));
+        }
+        timings.extend(day_timings);
+    }
+
+    if let Some(path) = save {
+        // Keep the entries of days that were not benchmarked this time
+        let mut saved = if path.exists() {
+            Baseline::load(path)?
+        } else {
+            Baseline::default()
+        };
+        saved.update(&timings);
+        std::fs::write(path, saved.to_string())
+            .map_err(|e| Error::io(&path.display().to_string(), e).to_string())?;
+    }
+    Ok(())
+}
+
+/// Read commands from stdin and print their output until `quit` or the end
+/// of input
+fn repl(day: &'static Day, input: Option<&InputSource>) -> Result<(), String> {
+    let (source, data) = read_input(day, input)?;
+    let mut session = Session::open(day, data.clone())
+        .map_err(|e| e.with_file(&source.to_string()).diagnostic(&data))?;
+    println!("Day {} with {}, `help` lists the commands", day.day, source);
+
+    let stdin = io::stdin();
+    let mut line = String::new();
+    loop {
+        print!("day{}> ", session.day());
+        io::stdout().flush().map_err(|e| e.to_string())?;
+        line.clear();
+        if stdin
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| e.to_string())?
+            == 0
+        {
+            println!();
+            return Ok(());
+        }
+        match session.eval(&line) {
+            Ok(Reply::Output(output)) => print!("{}", output),
+            Ok(Reply::Quit) => return Ok(()),
+            Err(e) => eprintln!("error: {}", e.trim_end()),
+        }
+    }
+}
+
+/// Print the statistics report of a day, or of the day 2 hand `table`
+fn report(
+    day: &Day,
+    input: Option<&InputSource>,
+    options: &ReportOptions,
+    table: Option<&HandTable>,
+) -> Result<(), String> {
+    let reporter = stats::REPORTS
+        .iter()
+        .find(|(d, _)| *d == day.day)
+        .map(|(_, reporter)| reporter)
+        .ok_or_else(|| format!("day {} has no report", day.day))?;
+    let (source, data) = read_input(day, input)?;
+    let report = match table {
+        Some(table) => day02::report(&data, options, Some(table)),
+        None => reporter(&data, options),
+    };
+    let report = report.map_err(|e| e.with_file(&source.to_string()).diagnostic(&data))?;
+    print!("{}", report);
+    Ok(())
+}
+
+/// Read the answers file, an empty registry if it does not exist yet
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    let name = path.display().to_string();
+    match read_input_file(path) {
+        Ok(data) => Answers::parse(&data).map_err(|e| e.with_file(&name).diagnostic(&data)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(Error::io(&name, e).to_string()),
+    }
+}
+
+/// Check the answers of the selected days against the answers file
+fn verify_days(
+    days: &[&Day],
+    input: Option<&InputSource>,
+    answers_file: Option<&Path>,
+    record: bool,
+) -> Result<(), String> {
+    let path = answers_file.map_or_else(answers::answers_path, Path::to_path_buf);
+    let mut answers = load_answers(&path)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let read = day_source(day, input).and_then(|source| Ok((source.read()?, source)));
+        let (data, source) = match read {
+            Ok(read) => read,
+            // A day without its input cannot be verified yet
+            Err(Error::Io { source: e, .. })
+                if input.is_none() && e.kind() == std::io::ErrorKind::NotFound =>
+            {
+                missing += Part::ALL.len();
+                println!("Day {}: no input", day.day);
+                continue;
+            }
+            Err(e) => return Err(e.to_string()),
+        };
+        let hash = answers::input_hash(&data);
+        let reports = (day.run)(&data, &Part::ALL)
+            .map_err(|e| e.with_file(&source.to_string()).diagnostic(&data))?;
+        for report in reports {
+            let name = format!("Day {} part {}", report.day, report.part);
+            match answers.check(report.day, &hash, report.part, &report.answer) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{}: pass", name);
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    println!(
+                        "{}: FAIL\n  expected: {}\n  got:      {}",
+                        name,
+                        expected.to_string().trim_end_matches('\n'),
+                        report.answer.to_string().trim_end_matches('\n')
+                    );
+                }
+                Verdict::Missing if record => {
+                    passed += 1;
+                    println!("{}: recorded", name);
+                    answers.insert(report.day, &hash, report.part, report.answer);
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!("{}: missing", name);
+                }
+            }
+        }
+    }
+
+    if record {
+        std::fs::write(&path, answers.to_string())
+            .map_err(|e| Error::io(&path.display().to_string(), e).to_string())?;
+    }
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("answers do not match {}", path.display())),
+    }
+}
+
+/// Submit the answer of one part, computing it unless given
+fn submit_answer(
+    day: &Day,
+    part: Part,
+    answer: Option<&str>,
+    input: Option<&InputSource>,
+) -> Result<(), String> {
+    let server = HttpFetcher::from_env()
+        .ok_or_else(|| format!("{} is not set, cannot submit", SESSION_ENV))?;
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let (source, data) = read_input(day, input)?;
+            let reports = (day.run)(&data, &[part])
+                .map_err(|e| e.with_file(&source.to_string()).diagnostic(&data))?;
+            reports
+                .into_iter()
+                .map(|report| report.answer.to_string())
+                .next()
+                .unwrap_or_default()
+        }
+    };
+
+    let mut log = SubmissionLog::load(SubmissionLog::default_path()).map_err(|e| e.to_string())?;
+    println!(
+        "Day {} part {}: submitting {}",
+        day.day,
+        part,
+        answer.trim_end()
+    );
+    let outcome = submit::submit(&server, &mut log, day.day, part, &answer, submit::now())
+        .map_err(|e| e.to_string())?;
+    println!("Day {} part {}: {}", day.day, part, outcome);
+    match outcome {
+        Outcome::Correct | Outcome::AlreadySolved => Ok(()),
+        _ => Err(format!("answer was not accepted: {}", outcome)),
+    }
+}
+
+/// `cargo` command building this crate
+fn cargo(root: &Path) -> process::Command {
+    let mut command = process::Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()));
+    command.current_dir(root);
+    command
+}
+
+/// Print an answer of a watched day and how it changed since the last run
+fn print_change(day: u8, part: Part, answer: &str, change: &Change) {
+    let answer = answer.trim_end_matches('\n');
+    match change {
+        Change::Changed { previous } if answer.contains('\n') => println!(
+            "Day {} part {} (changed):\n{}\nwas:\n{}",
+            day,
+            part,
+            answer,
+            previous.trim_end_matches('\n')
+        ),
+        Change::Changed { previous } => {
+            println!("Day {} part {}: {} (was {})", day, part, answer, previous)
+        }
+        _ if answer.contains('\n') => println!("Day {} part {}:\n{}", day, part, answer),
+        Change::Unchanged => println!("Day {} part {}: {} (unchanged)", day, part, answer),
+        Change::New => println!("Day {} part {}: {}", day, part, answer),
+    }
+}
+
+/// Rebuild and run a day and its example tests, returning the answers
+///
+/// The previous answers are kept when the day does not build or run.
+fn watch_round(
+    root: &Path,
+    day: u8,
+    input: Option<&InputSource>,
+    previous: Vec<(Part, String)>,
+) -> Result<Vec<(Part, String)>, String> {
+    let spawn_error = |e: std::io::Error| format!("cannot run cargo: {}", e);
+
+    let mut run = cargo(root);
+    run.args([
+        "run",
+        "--quiet",
+        "--bin",
+        "aoc",
+        "--",
+        "run",
+        &day.to_string(),
+    ]);
+    if let Some(input) = input {
+        run.arg("--input").arg(input.to_string());
+    }
+    let output = run.stderr(Stdio::inherit()).output().map_err(spawn_error)?;
+    let answers = if output.status.success() {
+        let answers = watch::parse_answers(&String::from_utf8_lossy(&output.stdout));
+        for ((part, answer), change) in answers.iter().zip(watch::compare(&previous, &answers)) {
+            print_change(day, *part, answer, &change);
+        }
+        answers
+    } else {
+        println!("Day {}: run failed", day);
+        previous
+    };
+
+    let tests = cargo(root)
+        .args(["test", "--quiet", "--test", "days", "--"])
+        .arg(format!("day{:02}", day))
+        .args(["--skip", "_answer"])
+        .output()
+        .map_err(spawn_error)?;
+    if tests.status.success() {
+        println!("Day {} examples: pass", day);
+    } else {
+        print!("{}", String::from_utf8_lossy(&tests.stdout));
+        eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+        println!("Day {} examples: FAIL", day);
+    }
+    Ok(answers)
+}
+
+/// Checkout that `new` and `watch` work on: the working directory or the
+/// nearest one above it with a `src/days/mod.rs`
+fn crate_root() -> Result<PathBuf, String> {
+    let dir = std::env::current_dir().map_err(|e| format!("working directory: {}", e))?;
+    dir.ancestors()
+        .find(|dir| dir.join("src/days/mod.rs").is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            format!(
+                "no src/days/mod.rs in {} or above, run aoc from the checkout",
+                dir.display()
+            )
+        })
+}
+
+/// Re-run a day whenever its module, input or examples change
+fn watch_day(day: &Day, input: Option<&InputSource>) -> Result<(), String> {
+    let root = &crate_root()?;
+    let input_path = match input {
+        Some(InputSource::File(path)) => path.clone(),
+        Some(InputSource::Stdin) => return Err("stdin cannot be watched".to_string()),
+        None => {
+            let cache = InputCache::new(input_dir());
+            cache
+                .get(YEAR, day.day)
+                .unwrap_or_else(|| cache.path(YEAR, day.day))
+        }
+    };
+    let mut watcher = Watcher::new(watch::watched_paths(root, day.day, &input_path));
+    for path in watcher.paths() {
+        println!(
+            "Watching {}",
+            path.strip_prefix(root).unwrap_or(path).display()
+        );
+    }
+
+    let mut answers = vec![];
+    loop {
+        println!();
+        answers = watch_round(root, day.day, input, answers)?;
+        for path in watcher.wait(WATCH_INTERVAL) {
+            println!(
+                "\nChanged {}",
+                path.strip_prefix(root).unwrap_or(&path).display()
+            );
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let env = std::env::var(LOG_ENV).ok().or_else(|| config.log.clone());
+    let command = match args::split_log_args(std::env::args().skip(1), env.as_deref()).and_then(
+        |(args, filter)| {
+            log::init(filter);
+            args::parse_args(args, &config)
+        },
+    ) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, args::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    config::init(config);
+
+    let result = match command {
+        Command::List => {
+            for day in days::DAYS {
+                println!("{}", day.day);
+            }
+            Ok(())
+        }
+        Command::Run {
+            selection,
+            part,
+            input,
+            format,
+            jobs,
+            memory,
+        } => selected_days(&selection).and_then(|days| match jobs {
+            Some(jobs) => run_parallel(&days, part, input.as_ref(), format, jobs),
+            None => days
+                .iter()
+                .try_for_each(|day| run_day(day, part, input.as_ref(), format, memory)),
+        }),
+        Command::Watch { day, input } => {
+            selected_days(&Selection::Day(day)).and_then(|days| watch_day(days[0], input.as_ref()))
+        }
+        Command::Repl { day, input } => {
+            selected_days(&Selection::Day(day)).and_then(|days| repl(days[0], input.as_ref()))
+        }
+        Command::Report {
+            day,
+            input,
+            options,
+            table,
+        } => selected_days(&Selection::Day(day))
+            .and_then(|days| report(days[0], input.as_ref(), &options, table.as_ref())),
+        Command::Fetch { selection } => selected_days(&selection).and_then(|days| {
+            let provider = InputProvider::from_env();
+            days.iter().try_for_each(|day| {
+                let path = provider.locate(YEAR, day.day).map_err(|e| e.to_string())?;
+                println!("Day {}: {}", day.day, path.display());
+                Ok(())
+            })
+        }),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+        } => selected_days(&Selection::Day(day))
+            .and_then(|days| submit_answer(days[0], part, answer.as_deref(), input.as_ref())),
+        Command::New { day, title } => crate_root().and_then(|root| {
+            scaffold::new_day(&root, day, title.as_deref().unwrap_or("TODO"))
+                .map(|files| {
+                    for file in files {
+                        println!("{}", file.strip_prefix(&root).unwrap_or(&file).display());
+                    }
+                })
+                .map_err(|e| e.to_string())
+        }),
+        Command::Verify {
+            selection,
+            input,
+            answers,
+            record,
+        } => selected_days(&selection)
+            .and_then(|days| verify_days(&days, input.as_ref(), answers.as_deref(), record)),
+        Command::Bench {
+            selection,
+            part,
+            input,
+            runs,
+            save,
+            baseline,
+            memory,
+        } => selected_days(&selection).and_then(|days| {
+            bench_days(
+                &days,
+                part,
+                input.as_ref(),
+                runs,
+                save.as_deref(),
+                baseline.as_deref(),
+                memory,
+            )
+        }),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e.trim_end());
+            ExitCode::FAILURE
+        }
+    }
+}
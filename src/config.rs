@@ -0,0 +1,171 @@
+//! Shared defaults of the runner read from `aoc.toml`
+//!
+//! The file is looked up from the working directory upwards, and in the
+//! user config directory (`$XDG_CONFIG_HOME/aoc`, `~/.config/aoc` or
+//! `%APPDATA%\aoc`). Keys of the project file take precedence over the ones
+//! of the user file; environment variables and command line options take
+//! precedence over both.
+//!
+//! ```toml
+//! input_dir = "../inputs"  # relative to the file
+//! format = "json"          # default of `aoc run --format`
+//! log = "day05=debug"      # like AOC_LOG
+//!
+//! [bench]
+//! runs = 20
+//!
+//! [server]
+//! base_url = "https://adventofcode.com"  # used by fetch and submit
+//! ```
+
+use crate::error::{Error, ParseError};
+use crate::input::read_input_file;
+use crate::log::{Filter, Level};
+use crate::parse::lines;
+use crate::toml::{integer, key_value, string};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Name of the config file
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Defaults set by the config files, `None` for keys that were not given
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Directory of the puzzle inputs
+    pub input_dir: Option<PathBuf>,
+    /// Output format of `aoc run`, `text` or `json`
+    pub format: Option<String>,
+    /// Log filter in the syntax of `AOC_LOG`
+    pub log: Option<String>,
+    /// Number of runs per part benchmarked by `aoc bench` and `cargo bench`
+    pub bench_runs: Option<usize>,
+    /// URL of the puzzle server inputs are fetched from and answers submitted to
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Parse the contents of a config file
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut config = Config::default();
+        let mut table = "";
+
+        for line in lines(input) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            if text.starts_with('[') {
+                table = match text.split('#').next().unwrap_or_default().trim() {
+                    "[bench]" => "bench",
+                    "[server]" => "server",
+                    _ => return Err(line.error(text, "`[bench]` or `[server]`")),
+                };
+                continue;
+            }
+
+            let (key, value) = key_value(&line)?;
+            match (table, key) {
+                ("", "input_dir") => config.input_dir = Some(string(&line, value)?.into()),
+                ("", "format") => {
+                    let format = string(&line, value)?;
+                    if format != "text" && format != "json" {
+                        return Err(line.error(value, "`\"text\"` or `\"json\"`"));
+                    }
+                    config.format = Some(format);
+                }
+                ("", "log") => {
+                    let spec = string(&line, value)?;
+                    if Filter::parse(&spec, Level::Warn).is_err() {
+                        return Err(line.error(value, "log filter like `\"day05=debug\"`"));
+                    }
+                    config.log = Some(spec);
+                }
+                ("", _) => {
+                    return Err(line.error(key, "`input_dir`, `format`, `log` or a table"));
+                }
+                ("bench", "runs") => {
+                    let runs = integer(&line, value, "run count")?;
+                    if runs == 0 {
+                        return Err(line.error(value, "positive run count"));
+                    }
+                    config.bench_runs = Some(runs);
+                }
+                ("bench", _) => return Err(line.error(key, "`runs`")),
+                (_, "base_url") => config.base_url = Some(string(&line, value)?),
+                (_, _) => return Err(line.error(key, "`base_url`")),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Read a config file, resolving `input_dir` relative to its directory
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let name = path.display().to_string();
+        let data = read_input_file(path).map_err(|e| Error::io(&name, e))?;
+        let mut config = Config::parse(&data).map_err(|e| e.with_file(&name))?;
+        if let (Some(dir), Some(base)) = (&config.input_dir, path.parent()) {
+            config.input_dir = Some(base.join(dir));
+        }
+        Ok(config)
+    }
+
+    /// Config of the project file found from `dir` upwards merged with the
+    /// user file in `user_dir`
+    pub fn load_from(dir: &Path, user_dir: Option<&Path>) -> Result<Self, Error> {
+        let project = dir
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file());
+        let user = user_dir
+            .map(|dir| dir.join(CONFIG_FILE))
+            .filter(|path| path.is_file());
+
+        let mut config = Config::default();
+        for path in [project, user].into_iter().flatten() {
+            config = config.or(Config::read(&path)?);
+        }
+        Ok(config)
+    }
+
+    /// Config of the working directory and the user config directory
+    pub fn load() -> Result<Self, Error> {
+        let dir = std::env::current_dir().map_err(|e| Error::io(".", e))?;
+        Config::load_from(&dir, user_config_dir().as_deref())
+    }
+
+    /// Keys of `self`, with the missing ones taken from `other`
+    pub fn or(self, other: Config) -> Self {
+        Config {
+            input_dir: self.input_dir.or(other.input_dir),
+            format: self.format.or(other.format),
+            log: self.log.or(other.log),
+            bench_runs: self.bench_runs.or(other.bench_runs),
+            base_url: self.base_url.or(other.base_url),
+        }
+    }
+}
+
+/// Directory of the user config file
+pub fn user_config_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    if cfg!(windows) {
+        return var("APPDATA").map(|dir| PathBuf::from(dir).join("aoc"));
+    }
+    match var("XDG_CONFIG_HOME") {
+        Some(dir) => Some(PathBuf::from(dir).join("aoc")),
+        None => var("HOME").map(|home| PathBuf::from(home).join(".config/aoc")),
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Install the config used by [`get`], only the first call has an effect
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+/// Config installed with [`init`], empty if there is none
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
@@ -0,0 +1,172 @@
+//! Day 1: Calorie Counting
+
+use crate::error::{Error, ParseError};
+use crate::num::Total;
+use crate::parse::{lines, read_groups, split_groups, Group, Line};
+use crate::solution::{Answer, Solution};
+use crate::stats::{percentile, Histogram, ReportFormat, ReportOptions};
+use crate::table;
+use crate::top::TopN;
+use std::io::BufRead;
+
+/// Calories of the items carried by one elf, counted in `T`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elf<T = i32> {
+    pub items: Vec<T>,
+    total: T,
+}
+
+impl<T: Total> Elf<T> {
+    /// Parse the lines of the group of the `number`th elf (from one)
+    ///
+    /// A total that does not fit in `T` is reported at the item that
+    /// overflowed it.
+    fn parse<'a>(
+        number: usize,
+        group: impl IntoIterator<Item = Line<'a>>,
+    ) -> Result<Self, ParseError> {
+        let mut elf = Elf::<T>::default();
+        for line in group {
+            let item: T = line.number(line.text, "calorie count")?;
+            elf.total = elf.total.checked_add(&item).ok_or_else(|| {
+                let expected = format!(
+                    "calorie count keeping the total of elf {} within {}",
+                    number,
+                    T::NAME
+                );
+                line.error(line.text, &expected)
+            })?;
+            elf.items.push(item);
+        }
+        Ok(elf)
+    }
+
+    pub fn total(&self) -> &T {
+        &self.total
+    }
+
+    /// Average calories of an item, `None` for an elf without items
+    pub fn mean(&self) -> Option<f64> {
+        match self.items.len() {
+            0 => None,
+            n => Some(self.total.to_f64() / n as f64),
+        }
+    }
+
+    pub fn max_item(&self) -> Option<&T> {
+        self.items.iter().max()
+    }
+}
+
+/// Parse the items carried by each elf
+pub fn parse_inventory<T: Total>(input: &str) -> Result<Vec<Elf<T>>, ParseError> {
+    let lines: Vec<_> = lines(input).collect();
+    split_groups(&lines)
+        .into_iter()
+        .enumerate()
+        .map(|(i, group)| Elf::parse(i + 1, group))
+        .collect()
+}
+
+/// Total calories carried by each elf
+pub fn totals<T: Total>(input: &str) -> Result<Vec<T>, ParseError> {
+    let elves = parse_inventory::<T>(input)?;
+    Ok(elves.into_iter().map(|elf| elf.total).collect())
+}
+
+/// Percentiles of the totals shown in the report
+const PERCENTILES: [u8; 7] = [0, 25, 50, 75, 90, 99, 100];
+
+/// Statistics of every elf, and of all of them in the text format
+///
+/// The CSV format holds the per elf table only. Totals are counted in `u64`
+/// like the solver does.
+pub fn report(input: &str, options: &ReportOptions) -> Result<String, ParseError> {
+    let elves = parse_inventory::<u64>(input)?;
+    let header = ["elf", "items", "total", "mean", "max_item"];
+    let rows: Vec<Vec<String>> = elves
+        .iter()
+        .enumerate()
+        .map(|(i, elf)| {
+            vec![
+                (i + 1).to_string(),
+                elf.items.len().to_string(),
+                elf.total().to_string(),
+                elf.mean()
+                    .map_or(String::new(), |mean| format!("{:.1}", mean)),
+                elf.max_item().map_or(String::new(), |max| max.to_string()),
+            ]
+        })
+        .collect();
+    if options.format == ReportFormat::Csv {
+        return Ok(table::csv(&header, &rows));
+    }
+
+    let mut totals: Vec<u64> = elves.iter().map(|elf| *elf.total()).collect();
+    totals.sort_unstable();
+    let items: usize = elves.iter().map(|elf| elf.items.len()).sum();
+    let percentiles: Vec<String> = PERCENTILES
+        .iter()
+        .filter_map(|&p| Some(format!("p{} {}", p, percentile(&totals, p)?)))
+        .collect();
+
+    let mut report = table::render(&header, &rows);
+    report += &format!("\n{} elves carrying {} items\n", elves.len(), items);
+    report += &format!("Totals: {}\n\n", percentiles.join(", "));
+    report += &Histogram::new(&totals, options.buckets).to_string();
+    Ok(report)
+}
+
+/// The `n` largest elf totals of an inventory read from `reader`, largest
+/// first
+///
+/// The inventory is read one elf at a time and at most `n` totals are kept,
+/// so the memory used does not grow with the size of the inventory. Totals
+/// are counted in `T`. `name` identifies the inventory in errors.
+pub fn top_totals<T: Total, R: BufRead>(reader: R, name: &str, n: usize) -> Result<Vec<T>, Error> {
+    let groups = read_groups(reader).map(|group| group.map_err(|e| Error::io(name, e)));
+    largest_totals(groups, n).map_err(|e| match e {
+        Error::Parse(e) => Error::Parse(e.with_file(name)),
+        e => e,
+    })
+}
+
+/// The `n` largest totals of the elves in `groups`, largest first
+fn largest_totals<T: Total, E: From<ParseError>>(
+    groups: impl Iterator<Item = Result<Group, E>>,
+    n: usize,
+) -> Result<Vec<T>, E> {
+    let mut top = TopN::new(n);
+    for (i, group) in groups.enumerate() {
+        top.push(Elf::<T>::parse(i + 1, group?.lines())?.total);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The three largest totals, largest first
+    type Input = Vec<u64>;
+    const DAY: u8 = 1;
+
+    /// Only the totals needed by the parts are kept, see [`top_totals`]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Lines of a string are always valid UTF-8, reading them cannot fail
+        let groups = read_groups(input.as_bytes())
+            .map(|group| Ok::<_, ParseError>(group.expect("reading a string failed")));
+        largest_totals(groups, 3)
+    }
+
+    /// Calories carried by the elf with the most calories
+    fn part1(calories: &Self::Input) -> Answer {
+        calories.first().copied().unwrap_or_default().into()
+    }
+
+    /// Sum of calories carried by the top three elves
+    ///
+    /// The sum is taken in `i128`, where three `u64` totals always fit.
+    fn part2(calories: &Self::Input) -> Answer {
+        Answer::Number(calories.iter().map(|&total| total as i128).sum())
+    }
+}
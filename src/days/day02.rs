@@ -0,0 +1,380 @@
+//! Day 2: Rock Paper Scissors
+
+use crate::error::ParseError;
+use crate::parse::lines;
+use crate::solution::{Answer, Solution};
+use crate::stats::{ReportFormat, ReportOptions};
+use crate::table;
+use crate::trace;
+
+/// Hand of a game, numbered in the cyclic order of its [`Rules`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand(pub usize);
+
+impl Hand {
+    pub const ROCK: Hand = Hand(0);
+    pub const PAPER: Hand = Hand(1);
+    pub const SCISSORS: Hand = Hand(2);
+}
+
+/// Result of a round for the own hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// Rules of rock paper scissors and its variants with more hands
+///
+/// The hands form a cycle in which each hand beats the `(n - 1) / 2` hands
+/// before it and loses to the ones after it, which makes every game with an
+/// odd number `n` of hands balanced. With `n = 3` this is rock, paper,
+/// scissors. A round scores the score of the own hand plus the score of its
+/// outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    hand_scores: Vec<i32>,
+    /// Scores of losing, a draw and winning
+    outcome_scores: [i32; 3],
+}
+
+impl Rules {
+    /// Game of the named hands in cyclic order, scored like the puzzle:
+    /// the hands 1, 2, 3... and the outcomes 0, 3 and 6
+    ///
+    /// Fails unless there is an odd number of at least three hands.
+    pub fn new<S: ToString>(names: &[S]) -> Result<Self, String> {
+        if names.len() < 3 || names.len().is_multiple_of(2) {
+            return Err(format!(
+                "a balanced game needs an odd number of at least 3 hands, not {}",
+                names.len()
+            ));
+        }
+        Ok(Rules {
+            names: names.iter().map(ToString::to_string).collect(),
+            hand_scores: (1..=names.len() as i32).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    /// The game of the puzzle
+    pub fn rock_paper_scissors() -> Self {
+        Rules::new(&["rock", "paper", "scissors"]).expect("three hands")
+    }
+
+    /// Rock paper scissors lizard Spock, ordered so that the cycle matches
+    /// the usual rules
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Rules::new(&["rock", "Spock", "paper", "lizard", "scissors"]).expect("five hands")
+    }
+
+    /// Replace the scores of the hands, one per hand in cyclic order
+    pub fn with_hand_scores(mut self, scores: &[i32]) -> Result<Self, String> {
+        if scores.len() != self.hands() {
+            return Err(format!(
+                "expected {} hand scores, not {}",
+                self.hands(),
+                scores.len()
+            ));
+        }
+        self.hand_scores = scores.to_vec();
+        Ok(self)
+    }
+
+    /// Replace the scores of losing, a draw and winning
+    pub fn with_outcome_scores(mut self, lose: i32, draw: i32, win: i32) -> Self {
+        self.outcome_scores = [lose, draw, win];
+        self
+    }
+
+    /// Number of hands
+    pub fn hands(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn name(&self, hand: Hand) -> &str {
+        &self.names[hand.0]
+    }
+
+    /// Outcome of playing `own` against `other`
+    pub fn outcome(&self, own: Hand, other: Hand) -> Outcome {
+        let n = self.hands();
+        match (own.0 + n - other.0) % n {
+            0 => Outcome::Draw,
+            distance if distance <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// Hand to play against `other` for the given outcome
+    ///
+    /// With more than three hands several hands win or lose against `other`;
+    /// the one with the highest score is chosen, the nearest in the cycle if
+    /// they score the same.
+    pub fn hand_for(&self, other: Hand, outcome: Outcome) -> Hand {
+        let n = self.hands();
+        (0..n)
+            .map(|distance| match outcome {
+                Outcome::Lose => Hand((other.0 + n - distance) % n),
+                _ => Hand((other.0 + distance) % n),
+            })
+            .filter(|&hand| self.outcome(hand, other) == outcome)
+            .fold(None, |best: Option<Hand>, hand| match best {
+                Some(best) if self.hand_score(best) >= self.hand_score(hand) => Some(best),
+                _ => Some(hand),
+            })
+            .expect("every outcome has a hand")
+    }
+
+    pub fn hand_score(&self, hand: Hand) -> i32 {
+        self.hand_scores[hand.0]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        self.outcome_scores[outcome as usize]
+    }
+
+    /// Score of a round for the player of `own`
+    pub fn score(&self, own: Hand, other: Hand) -> i32 {
+        self.hand_score(own) + self.outcome_score(self.outcome(own, other))
+    }
+}
+
+/// Second column of the strategy guide; its meaning depends on the part
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Xyz {
+    X,
+    Y,
+    Z,
+}
+
+impl Xyz {
+    const ALL: [Xyz; 3] = [Xyz::X, Xyz::Y, Xyz::Z];
+
+    /// Position of the letter, 0 for `X`
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Interpretation of the second column of the strategy guide
+pub trait Decoder {
+    /// Own hand to play against `other` for the letter `xyz`
+    fn decode(&self, rules: &Rules, other: Hand, xyz: Xyz) -> Hand;
+}
+
+/// Decoder of the letters `X`, `Y` and `Z` as the hands of the table
+///
+/// The default table is the reading of part 1: rock, paper and scissors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandTable(pub [Hand; 3]);
+
+impl Default for HandTable {
+    fn default() -> Self {
+        HandTable([Hand::ROCK, Hand::PAPER, Hand::SCISSORS])
+    }
+}
+
+impl HandTable {
+    /// Every table of three different hands of `rules`
+    pub fn permutations(rules: &Rules) -> Vec<HandTable> {
+        let hands = || (0..rules.hands()).map(Hand);
+        let mut tables = vec![];
+        for x in hands() {
+            for y in hands().filter(|&y| y != x) {
+                for z in hands().filter(|&z| z != x && z != y) {
+                    tables.push(HandTable([x, y, z]));
+                }
+            }
+        }
+        tables
+    }
+
+    /// Table of the hands named by `spec`, like `"X=rock,Y=paper,Z=scissors"`
+    ///
+    /// Letters that are not named keep their hand of the default table. Every
+    /// letter must read as a different hand, like the tables of
+    /// [`HandTable::permutations`].
+    pub fn parse(rules: &Rules, spec: &str) -> Result<Self, String> {
+        let mut table = HandTable::default();
+        for entry in spec.split(',').map(str::trim) {
+            let (letter, name) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected `letter=hand`, not `{}`", entry))?;
+            let index = match letter.trim() {
+                "X" => 0,
+                "Y" => 1,
+                "Z" => 2,
+                letter => return Err(format!("expected `X`, `Y` or `Z`, not `{}`", letter)),
+            };
+            let name = name.trim();
+            table.0[index] = (0..rules.hands())
+                .map(Hand)
+                .find(|&hand| rules.name(hand) == name)
+                .ok_or_else(|| format!("unknown hand `{}`", name))?;
+        }
+        for (i, hand) in table.0.iter().enumerate() {
+            if table.0[..i].contains(hand) {
+                let name = rules.name(*hand);
+                return Err(format!("hand `{}` is given for more than one letter", name));
+            }
+        }
+        Ok(table)
+    }
+
+    /// Table as `X=rock, Y=paper, Z=scissors`
+    pub fn describe(&self, rules: &Rules) -> String {
+        let entries: Vec<String> = Xyz::ALL
+            .iter()
+            .map(|&xyz| format!("{:?}={}", xyz, rules.name(self.0[xyz.index()])))
+            .collect();
+        entries.join(", ")
+    }
+}
+
+impl Decoder for HandTable {
+    fn decode(&self, _rules: &Rules, _other: Hand, xyz: Xyz) -> Hand {
+        self.0[xyz.index()]
+    }
+}
+
+/// Decoder of the letters `X`, `Y` and `Z` as the outcomes of the table
+///
+/// The default table is the reading of part 2: lose, draw and win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomeTable(pub [Outcome; 3]);
+
+impl Default for OutcomeTable {
+    fn default() -> Self {
+        OutcomeTable([Outcome::Lose, Outcome::Draw, Outcome::Win])
+    }
+}
+
+impl Decoder for OutcomeTable {
+    fn decode(&self, rules: &Rules, other: Hand, xyz: Xyz) -> Hand {
+        rules.hand_for(other, self.0[xyz.index()])
+    }
+}
+
+/// Total score of the rounds when the guide is read with `decoder`
+pub fn total_score(rules: &Rules, rounds: &[(Hand, Xyz)], decoder: &dyn Decoder) -> i64 {
+    let mut score = 0;
+    for &(other, xyz) in rounds {
+        let own = decoder.decode(rules, other, xyz);
+        trace!("{},{}", rules.name(other), rules.name(own));
+        score += rules.score(own, other) as i64;
+    }
+    score
+}
+
+/// Scores of every [`HandTable`] permutation, best first
+///
+/// Tables with the same score keep the order of
+/// [`HandTable::permutations`].
+pub fn rank_hand_tables(rules: &Rules, rounds: &[(Hand, Xyz)]) -> Vec<(HandTable, i64)> {
+    let mut ranking: Vec<(HandTable, i64)> = HandTable::permutations(rules)
+        .into_iter()
+        .map(|table| (table, total_score(rules, rounds, &table)))
+        .collect();
+    ranking.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    ranking
+}
+
+/// Score of the strategy guide for every reading of `X`, `Y` and `Z` as
+/// hands, best first, or only for the given `table` and its rank
+pub fn report(
+    input: &str,
+    options: &ReportOptions,
+    table: Option<&HandTable>,
+) -> Result<String, ParseError> {
+    let rounds = parse_input(input)?;
+    let rules = Rules::rock_paper_scissors();
+    let ranking = rank_hand_tables(&rules, &rounds);
+    let header = ["X", "Y", "Z", "score"];
+    let row = |(table, score): &(HandTable, i64)| {
+        let mut row: Vec<String> = table.0.iter().map(|&h| rules.name(h).into()).collect();
+        row.push(score.to_string());
+        row
+    };
+
+    if let Some(&given) = table {
+        let score = total_score(&rules, &rounds, &given);
+        let rows = vec![row(&(given, score))];
+        if options.format == ReportFormat::Csv {
+            return Ok(table::csv(&header, &rows));
+        }
+        // Readings scoring the same share the rank
+        let rank = 1 + ranking.iter().filter(|(_, s)| *s > score).count();
+        let mut report = table::render(&header, &rows);
+        report += &format!(
+            "\n{} scores {}, rank {} of {}\n",
+            given.describe(&rules),
+            score,
+            rank,
+            ranking.len()
+        );
+        return Ok(report);
+    }
+
+    let rows: Vec<Vec<String>> = ranking.iter().map(row).collect();
+    if options.format == ReportFormat::Csv {
+        return Ok(table::csv(&header, &rows));
+    }
+
+    let mut report = table::render(&header, &rows);
+    if let Some((best, score)) = ranking.first() {
+        report += &format!("\nBest reading: {} ({})\n", best.describe(&rules), score);
+    }
+    let outcomes = total_score(&rules, &rounds, &OutcomeTable::default());
+    report += &format!("Read as lose, draw and win: {}\n", outcomes);
+    Ok(report)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(Hand, Xyz)>;
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Total score when the second column is own hand
+    fn part1(rounds: &Self::Input) -> Answer {
+        let rules = Rules::rock_paper_scissors();
+        total_score(&rules, rounds, &HandTable::default()).into()
+    }
+
+    /// Total score when the second column is the desired result
+    fn part2(rounds: &Self::Input) -> Answer {
+        let rules = Rules::rock_paper_scissors();
+        total_score(&rules, rounds, &OutcomeTable::default()).into()
+    }
+}
+
+/// Parse the strategy guide as list of opponent hands and `X`/`Y`/`Z` columns
+fn parse_input(input: &str) -> Result<Vec<(Hand, Xyz)>, ParseError> {
+    let mut rounds = vec![];
+    for line in lines(input) {
+        let abc = line.word(0, "`A`, `B` or `C`")?;
+        let hand = match abc {
+            "A" => Hand::ROCK,
+            "B" => Hand::PAPER,
+            "C" => Hand::SCISSORS,
+            _ => return Err(line.error(abc, "`A`, `B` or `C`")),
+        };
+        let xyz = line.word(1, "`X`, `Y` or `Z`")?;
+        let xyz = match xyz {
+            "X" => Xyz::X,
+            "Y" => Xyz::Y,
+            "Z" => Xyz::Z,
+            _ => return Err(line.error(xyz, "`X`, `Y` or `Z`")),
+        };
+        line.expect_words(2)?;
+        rounds.push((hand, xyz));
+    }
+    Ok(rounds)
+}
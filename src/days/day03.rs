@@ -0,0 +1,95 @@
+//! Day 3: Rucksack Reorganization
+
+use crate::error::ParseError;
+use crate::parse::{lines, unexpected_end};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+
+/// Priority of an item: `a..z` are 1..26 and `A..Z` are 27..52
+fn priority(item: char) -> i32 {
+    if item.is_uppercase() {
+        (item as i32) - 'A' as i32 + 27
+    } else {
+        (item as i32) - 'a' as i32 + 1
+    }
+}
+
+/// Split the rucksack into its two compartments
+fn compartments(rucksack: &str) -> (&str, &str) {
+    rucksack.split_at(rucksack.len() / 2)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rucksacks = vec![];
+        for line in lines(input) {
+            if let Some((offset, c)) = line
+                .text
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_alphabetic())
+            {
+                return Err(line.error_at(offset, "item letter", &c.to_string()));
+            }
+            if !line.text.len().is_multiple_of(2) {
+                return Err(line.error_at_end("even number of items"));
+            }
+            rucksacks.push(line.text.to_string());
+        }
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(unexpected_end(input, "group of three rucksacks"));
+        }
+        Ok(rucksacks)
+    }
+
+    /// Sum of priorities of the items found in both compartments
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        let mut sum = 0;
+        for rucksack in rucksacks {
+            let line = compartments(rucksack);
+            'outer: for a in line.0.chars() {
+                for b in line.1.chars() {
+                    if a == b {
+                        let priority = priority(a);
+                        trace!("{}: {}", a, priority);
+                        sum += priority;
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        sum.into()
+    }
+
+    /// Sum of priorities of the badge items shared by each group of three elves
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        let mut sum = 0;
+
+        for group in rucksacks.chunks(3) {
+            if let Some(rucksack1) = group.first() {
+                let mut badge_match_count = 0;
+                'outer: for item in rucksack1.chars() {
+                    'inner: for rucksack2 in &group[1..=2] {
+                        if rucksack2.contains(item) {
+                            badge_match_count += 1;
+                            if badge_match_count == 2 {
+                                let priority = priority(item);
+                                trace!("{}: {}", item, priority);
+                                sum += priority;
+                                break 'outer;
+                            }
+                        } else {
+                            badge_match_count = 0;
+                            break 'inner;
+                        }
+                    }
+                }
+            }
+        }
+        sum.into()
+    }
+}
@@ -0,0 +1,81 @@
+//! Day 4: Camp Cleanup
+
+use crate::error::ParseError;
+use crate::parse::{lines, Line};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Range {
+    min: u32,
+    max: u32,
+}
+
+/// Formats the range as in the input (`min-max`)
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.min, self.max)
+    }
+}
+
+/// Parse the input and return list of range pairs
+fn parse_input(input: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    // Local function called to perform same operation twice
+    fn split_sections(line: &Line, section: &str) -> Result<Range, ParseError> {
+        let (min, max) = section
+            .split_once('-')
+            .ok_or_else(|| line.error(section, "section range `min-max`"))?;
+        Ok(Range {
+            min: line.number(min, "section number")?,
+            max: line.number(max, "section number")?,
+        })
+    }
+
+    let mut assignments = vec![];
+    for line in lines(input) {
+        let (left, right) = line
+            .text
+            .split_once(',')
+            .ok_or_else(|| line.error_at_end("`,`"))?;
+        assignments.push((split_sections(&line, left)?, split_sections(&line, right)?));
+    }
+    Ok(assignments)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(Range, Range)>;
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Number of pairs where one range fully contains the other
+    fn part1(assignments: &Self::Input) -> Answer {
+        let mut count = 0;
+        for ass in assignments {
+            trace!("{:?}", ass);
+            if (ass.0.min >= ass.1.min && ass.0.max <= ass.1.max)
+                || (ass.1.min >= ass.0.min && ass.1.max <= ass.0.max)
+            {
+                count += 1;
+            }
+        }
+        count.into()
+    }
+
+    /// Number of pairs where the ranges overlap
+    fn part2(assignments: &Self::Input) -> Answer {
+        let mut count = 0;
+        for ass in assignments {
+            trace!("{:?}", ass);
+            if ass.0.min <= ass.1.max && ass.0.max >= ass.1.min {
+                count += 1;
+            }
+        }
+        count.into()
+    }
+}
@@ -0,0 +1,308 @@
+//! Day 5: Supply Stacks
+
+use crate::error::ParseError;
+use crate::parse::lines;
+use crate::repl::{self, Explorer};
+use crate::solution::{Answer, Solution};
+use crate::{debug, trace};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt;
+
+#[derive(Default, Debug, Clone)]
+struct Command {
+    from: usize,
+    to: usize,
+    amount: u32,
+}
+
+/// Crane model, moving one crate at a time or several at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+#[derive(Default, Clone)]
+pub struct InputData {
+    board: HashMap<usize, VecDeque<char>>,
+    commands: Vec<Command>,
+}
+
+impl InputData {
+    /// Debug print the board after a command
+    fn print_board(&self, cmd: &Command) {
+        debug!("move {} from {} to {}", cmd.amount, cmd.from, cmd.to);
+        for val in self.board.iter() {
+            trace!("{:?}", val);
+        }
+    }
+
+    /// Execute a command with the given crane
+    fn apply(&mut self, cmd: &Command, crane: Crane) {
+        match crane {
+            Crane::CrateMover9000 => {
+                for _ in 0..cmd.amount {
+                    let mut c = 'a';
+                    if let Some(from_stack) = self.board.get_mut(&cmd.from) {
+                        if let Some(ch) = from_stack.pop_back() {
+                            c = ch;
+                        }
+                    }
+
+                    if let Some(to_stack) = self.board.get_mut(&cmd.to) {
+                        to_stack.push_back(c);
+                    }
+                }
+            }
+            Crane::CrateMover9001 => {
+                let mut stack = vec![];
+
+                if let Some(from_stack) = self.board.get_mut(&cmd.from) {
+                    for _ in 0..cmd.amount {
+                        if let Some(ch) = from_stack.pop_back() {
+                            stack.push(ch);
+                        }
+                    }
+                }
+
+                if let Some(to_stack) = self.board.get_mut(&cmd.to) {
+                    for ch in stack.iter().rev() {
+                        to_stack.push_back(*ch);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drawing of the stacks with the stack numbers below them
+    fn layout(&self) -> String {
+        let stacks = self.board.len();
+        let height = self.board.values().map(VecDeque::len).max().unwrap_or(0);
+        let mut layout = String::new();
+        for row in (0..height).rev() {
+            let cells: Vec<String> = (1..=stacks)
+                .map(|i| match self.board.get(&i).and_then(|s| s.get(row)) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            layout += &format!("{}\n", cells.join(" "));
+        }
+        // Numbers start below the crate letters, which keeps two digits aligned
+        let numbers: Vec<String> = (1..=stacks).map(|i| format!(" {:<2}", i)).collect();
+        layout + &numbers.join(" ") + "\n"
+    }
+
+    /// Crates on top of each stack, in the order of the stack numbers
+    fn top_crates(&self) -> String {
+        let mut stacks: Vec<_> = self.board.iter().collect();
+        stacks.sort_by_key(|(i, _)| **i);
+        stacks
+            .into_iter()
+            .filter_map(|(_, stack)| stack.back())
+            .collect()
+    }
+}
+
+/// Formats the crate layout and the commands as in the input
+///
+/// Stacks are numbered from one without gaps and every crate row is padded
+/// to the full width of the layout.
+impl fmt::Display for InputData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.layout())?;
+        for cmd in &self.commands {
+            writeln!(f, "move {} from {} to {}", cmd.amount, cmd.from, cmd.to)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = InputData;
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Move crates one at a time (CrateMover 9000)
+    fn part1(data: &Self::Input) -> Answer {
+        rearrange(data, Crane::CrateMover9000).into()
+    }
+
+    /// Move multiple crates at once (CrateMover 9001)
+    fn part2(data: &Self::Input) -> Answer {
+        rearrange(data, Crane::CrateMover9001).into()
+    }
+}
+
+/// Execute all commands and return the crates on top of the stacks
+fn rearrange(data: &InputData, crane: Crane) -> String {
+    let mut data = data.clone();
+    let commands = std::mem::take(&mut data.commands);
+    for cmd in commands.iter() {
+        data.apply(cmd, crane);
+
+        // Debug print
+        data.print_board(cmd);
+    }
+    data.top_crates()
+}
+
+/// REPL state: the stacks after the first `done` commands
+struct Stacks {
+    input: InputData,
+    current: InputData,
+    done: usize,
+    crane: Crane,
+}
+
+impl Stacks {
+    /// Restart from the initial layout and execute the first `count` commands
+    fn goto(&mut self, count: usize) {
+        if count < self.done {
+            self.current = self.input.clone();
+            self.done = 0;
+        }
+        let count = count.min(self.input.commands.len());
+        for cmd in &self.input.commands[self.done..count] {
+            self.current.apply(cmd, self.crane);
+        }
+        self.done = count;
+    }
+
+    fn stacks(&self) -> String {
+        format!(
+            "after move {} of {}:\n{}",
+            self.done,
+            self.input.commands.len(),
+            self.current.layout()
+        )
+    }
+}
+
+/// Open the REPL explorer of the crate stacks
+pub fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    let input = parse_input(input)?;
+    Ok(Box::new(Stacks {
+        current: input.clone(),
+        input,
+        done: 0,
+        crane: Crane::CrateMover9000,
+    }))
+}
+
+impl Explorer for Stacks {
+    fn commands(&self) -> &'static [repl::Command] {
+        &[
+            repl::Command {
+                usage: "stacks",
+                help: "show the stacks",
+            },
+            repl::Command {
+                usage: "step [n]",
+                help: "execute the next n moves (default 1)",
+            },
+            repl::Command {
+                usage: "goto <k>",
+                help: "show the stacks after move k",
+            },
+            repl::Command {
+                usage: "crane <9000|9001>",
+                help: "switch the crane model and replay the moves",
+            },
+            repl::Command {
+                usage: "top",
+                help: "crates on top of the stacks",
+            },
+        ]
+    }
+
+    fn execute(&mut self, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "stacks" => Ok(self.stacks()),
+            "step" => {
+                let count = repl::optional_arg(args, 0, "move count")?.unwrap_or(1);
+                let start = self.done;
+                self.goto(self.done.saturating_add(count));
+                let moves: String = self.input.commands[start..self.done]
+                    .iter()
+                    .map(|cmd| format!("move {} from {} to {}\n", cmd.amount, cmd.from, cmd.to))
+                    .collect();
+                Ok(moves + &self.stacks())
+            }
+            "goto" => {
+                self.goto(repl::arg(args, 0, "move number")?);
+                Ok(self.stacks())
+            }
+            "crane" => {
+                self.crane = match repl::arg::<u32>(args, 0, "crane model")? {
+                    9000 => Crane::CrateMover9000,
+                    9001 => Crane::CrateMover9001,
+                    model => return Err(format!("unknown crane model {}", model)),
+                };
+                let done = self.done;
+                self.goto(0);
+                self.goto(done);
+                Ok(self.stacks())
+            }
+            _ => Ok(format!("{}\n", self.current.top_crates())),
+        }
+    }
+}
+
+/// Parse the crate layout and the list of commands
+fn parse_input(input: &str) -> Result<InputData, ParseError> {
+    let mut data = InputData::default();
+    let mut lines = lines(input);
+
+    // Parse crate layout into map of deques
+    for line in lines.by_ref() {
+        if line.text.is_empty() {
+            break;
+        }
+        for (i, c) in line.text.chars().enumerate() {
+            let key = (i as f32 / 4.0).ceil() as usize;
+            if c.is_ascii_alphabetic() {
+                let v = data.board.entry(key).or_default();
+                v.push_front(c);
+            } else if c.is_ascii_digit() {
+                // Stack numbers below the crates (stacks may start empty)
+                data.board.entry(key).or_default();
+            }
+        }
+    }
+
+    // Parse command list
+    for line in lines {
+        let parts = line.words();
+        for (i, keyword) in [(0, "move"), (2, "from"), (4, "to")] {
+            let word = line.word(i, &format!("`{}`", keyword))?;
+            if word != keyword {
+                return Err(line.error(word, &format!("`{}`", keyword)));
+            }
+        }
+        line.word(5, "stack number")?;
+        line.expect_words(6)?;
+
+        let command = Command {
+            from: line.number(parts[3], "stack number")?,
+            to: line.number(parts[5], "stack number")?,
+            amount: line.number(parts[1], "crate count")?,
+        };
+        for stack in [parts[3], parts[5]] {
+            if !data
+                .board
+                .contains_key(&line.number(stack, "stack number")?)
+            {
+                return Err(line.error(stack, "existing stack number"));
+            }
+        }
+        data.commands.push(command);
+    }
+    Ok(data)
+}
@@ -0,0 +1,59 @@
+//! Day 6: Tuning Trouble
+
+use crate::error::ParseError;
+use crate::parse::unexpected_end;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+fn has_unique_elements<T>(iter: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash,
+{
+    let mut unique = HashSet::new();
+    iter.into_iter().all(move |x| unique.insert(x))
+}
+
+/// Number of bytes read until the last `marker_len` bytes are all different
+fn find_marker(input: &str, marker_len: usize) -> usize {
+    let mut byte_count = 0;
+    let mut win = VecDeque::<u8>::new();
+    for b in input.bytes() {
+        win.push_back(b);
+        if win.len() > marker_len {
+            win.pop_front();
+            byte_count += 1;
+        }
+        if win.len() == marker_len && has_unique_elements(&win) {
+            break;
+        }
+    }
+    byte_count + marker_len
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let datastream = input.trim_end();
+        if datastream.is_empty() {
+            return Err(unexpected_end(input, "datastream"));
+        }
+        Ok(datastream.to_string())
+    }
+
+    /// Position of the start-of-packet marker
+    fn part1(input: &Self::Input) -> Answer {
+        find_marker(input, 4).into()
+    }
+
+    /// Position of the start-of-message marker
+    fn part2(input: &Self::Input) -> Answer {
+        find_marker(input, 14).into()
+    }
+}
@@ -0,0 +1,265 @@
+//! Day 7: No Space Left On Device
+
+use crate::error::ParseError;
+use crate::parse::lines;
+use crate::repl::{self, Explorer};
+use crate::solution::{Answer, Solution};
+use crate::{debug, info, trace};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Default)]
+pub struct FileNode {
+    is_file: bool,
+    name: String,
+    size: u32,
+    parent: Option<FileNodePtr>,
+    children: Vec<FileNodePtr>,
+}
+
+/// Type definition for smart pointer
+pub type FileNodePtr = Rc<RefCell<FileNode>>;
+
+/// Recursive function for drawing the entire node hierarchy
+fn draw_node_tree(node: FileNodePtr, tabs: usize) -> String {
+    let indent = "  ".repeat(tabs);
+    let mut tree = if node.borrow().is_file {
+        format!(
+            "{}- {} (file, size={})\n",
+            indent,
+            node.borrow().name,
+            node.borrow().size
+        )
+    } else {
+        format!("{}- {} (dir)\n", indent, node.borrow().name)
+    };
+    for child_node in node.borrow().children.iter() {
+        tree += &draw_node_tree(child_node.clone(), tabs + 1);
+    }
+    tree
+}
+
+/// Recursive function for calculating total size of the node and its children
+fn calculate_node_size(node: FileNodePtr) -> u64 {
+    let mut size = node.borrow().size as u64;
+    for child_node in node.borrow().children.iter() {
+        size += calculate_node_size(child_node.clone());
+    }
+    size
+}
+
+/// Recursive function for calculating sum of small (< 100000) folders
+fn sum_of_small_folders(node: FileNodePtr, sum: &mut u64) -> u64 {
+    let mut size = node.borrow().size as u64;
+    for child_node in node.borrow().children.iter() {
+        size += sum_of_small_folders(child_node.clone(), sum);
+    }
+    if !node.borrow().is_file && size <= 100000 {
+        *sum += size;
+    }
+    size
+}
+
+/// Recursive function for finding folders larger than limit
+fn find_large_folders(node: FileNodePtr, limit: u64, smallest: &mut Vec<(String, u64)>) -> u64 {
+    let mut size = node.borrow().size as u64;
+    for child_node in node.borrow().children.iter() {
+        size += find_large_folders(child_node.clone(), limit, &mut *smallest);
+    }
+    if !node.borrow().is_file && size >= limit {
+        smallest.push((node.borrow().name.to_string(), size));
+    }
+    size
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = FileNodePtr;
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Sum of the sizes of directories with a total size of at most 100000
+    fn part1(file_tree: &Self::Input) -> Answer {
+        trace!("file tree:\n{}", draw_node_tree(file_tree.clone(), 0));
+
+        let mut sum = 0;
+        sum_of_small_folders(file_tree.clone(), &mut sum);
+        sum.into()
+    }
+
+    /// Size of the smallest directory that frees up enough space for the update
+    fn part2(file_tree: &Self::Input) -> Answer {
+        trace!("file tree:\n{}", draw_node_tree(file_tree.clone(), 0));
+
+        let total_size = calculate_node_size(file_tree.clone());
+        info!("Total size of file hierarchy = {}", total_size);
+
+        // Nothing has to be freed if there already is enough unused space
+        let size_required = 30000000u64.saturating_sub(70000000u64.saturating_sub(total_size));
+        info!("Size required to free = {}", size_required);
+
+        let mut largest = vec![];
+        find_large_folders(file_tree.clone(), size_required, &mut largest);
+
+        let mut m = 0;
+        for s in largest {
+            if m == 0 || s.1 < m {
+                m = s.1;
+            }
+            debug!("Large enough folder {}: {}", s.0, s.1);
+        }
+        m.into()
+    }
+}
+
+/// Find a file or directory by its absolute path, e.g. `/a/e`
+fn resolve(root: &FileNodePtr, path: &str) -> Result<FileNodePtr, String> {
+    let relative = path
+        .strip_prefix('/')
+        .ok_or_else(|| format!("path `{}` does not start with `/`", path))?;
+    let mut node = root.clone();
+    for name in relative.split('/').filter(|name| !name.is_empty()) {
+        let child = node
+            .borrow()
+            .children
+            .iter()
+            .find(|child| child.borrow().name == name)
+            .cloned();
+        node = child.ok_or_else(|| format!("no such file or directory `{}`", path))?;
+    }
+    Ok(node)
+}
+
+/// REPL state: the file tree
+struct Tree {
+    root: FileNodePtr,
+}
+
+impl Tree {
+    fn node(&self, args: &[&str]) -> Result<FileNodePtr, String> {
+        let path = repl::optional_arg::<String>(args, 0, "path")?;
+        resolve(&self.root, path.as_deref().unwrap_or("/"))
+    }
+}
+
+/// Open the REPL explorer of the file tree
+pub fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    Ok(Box::new(Tree {
+        root: parse_input(input)?,
+    }))
+}
+
+impl Explorer for Tree {
+    fn commands(&self) -> &'static [repl::Command] {
+        &[
+            repl::Command {
+                usage: "tree [path]",
+                help: "draw the tree below a directory (default /)",
+            },
+            repl::Command {
+                usage: "ls [path]",
+                help: "list a directory with the total sizes of its entries",
+            },
+            repl::Command {
+                usage: "du [path]",
+                help: "total size of a file or directory",
+            },
+        ]
+    }
+
+    fn execute(&mut self, name: &str, args: &[&str]) -> Result<String, String> {
+        let node = self.node(args)?;
+        match name {
+            "tree" => Ok(draw_node_tree(node, 0)),
+            "ls" => {
+                let mut listing = String::new();
+                for child in node.borrow().children.iter() {
+                    let kind = if child.borrow().is_file {
+                        "file"
+                    } else {
+                        "dir"
+                    };
+                    listing += &format!(
+                        "{} ({}, size={})\n",
+                        child.borrow().name,
+                        kind,
+                        calculate_node_size(child.clone())
+                    );
+                }
+                Ok(listing)
+            }
+            _ => Ok(format!("{}\n", calculate_node_size(node))),
+        }
+    }
+}
+
+/// Parse the terminal output and construct the file node hierarchy
+fn parse_input(input: &str) -> Result<FileNodePtr, ParseError> {
+    let root_node = Rc::new(RefCell::new(FileNode::default()));
+    let mut current_node = root_node.clone();
+
+    for line in lines(input) {
+        // Commands start with '$'
+        if line.text.starts_with('$') {
+            // Commands are either "cd" or "ls"
+            let command = line.word(1, "`cd` or `ls`")?;
+            if command == "cd" {
+                let name = line.word(2, "directory name")?.to_string();
+                line.expect_words(3)?;
+
+                // Handle "cd" to root directory
+                if name.contains('/') {
+                    root_node.borrow_mut().name = name;
+                    current_node = root_node.clone();
+                // Handle "cd" to parent directory
+                } else if name == ".." {
+                    if let Some(parent) = &current_node.clone().borrow().parent {
+                        current_node = parent.clone();
+                    }
+                // Handle "cd" to one of the child directories
+                } else {
+                    for child_node in current_node.clone().borrow().children.iter() {
+                        if child_node.borrow().name == name {
+                            current_node = child_node.clone();
+                        }
+                    }
+                }
+            } else if command == "ls" {
+                line.expect_words(2)?;
+            } else {
+                return Err(line.error(command, "`cd` or `ls`"));
+            }
+        // Other lines must be `ls` output lines
+        // Directories start with "dir" keyword
+        } else {
+            let size = line.word(0, "`dir` or file size")?;
+            let name = line.word(1, "file name")?.to_string();
+            line.expect_words(2)?;
+
+            let node = if size == "dir" {
+                Rc::new(RefCell::new(FileNode {
+                    is_file: false,
+                    name,
+                    parent: Some(current_node.clone()),
+                    ..Default::default()
+                }))
+            } else {
+                Rc::new(RefCell::new(FileNode {
+                    is_file: true,
+                    name,
+                    parent: Some(current_node.clone()),
+                    size: line.number(size, "`dir` or file size")?,
+                    ..Default::default()
+                }))
+            };
+
+            current_node.borrow_mut().children.push(node);
+        }
+    }
+
+    Ok(root_node)
+}
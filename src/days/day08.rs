@@ -0,0 +1,181 @@
+//! Day 8: Treetop Tree House
+
+use crate::error::ParseError;
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::parse::{digit_grid, lines, unexpected_end};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+
+/// Parse the tree heights into a grid
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    let lines: Vec<_> = lines(input).collect();
+    if lines.is_empty() {
+        return Err(unexpected_end(input, "tree heights"));
+    }
+    digit_grid(&lines)
+}
+
+fn is_visible(grid: &Grid<u32>, point: &Point<usize>) -> bool {
+    if point.x == 0 || point.x == grid.width() - 1 || point.y == 0 || point.y == grid.height() - 1 {
+        return true;
+    } else {
+        let val1 = grid.get(point).unwrap();
+        // Up
+        let mut y = point.y - 1;
+        loop {
+            if let Some(val2) = grid.get(&Point::new(point.x, y)) {
+                if val2 >= val1 {
+                    break;
+                }
+            }
+
+            if y > 0 {
+                y -= 1;
+            } else {
+                return true;
+            }
+        }
+        // Down
+        let mut y = point.y + 1;
+        loop {
+            if let Some(val2) = grid.get(&Point::new(point.x, y)) {
+                if val2 >= val1 {
+                    break;
+                }
+            }
+
+            if y < grid.height() {
+                y += 1;
+            } else {
+                return true;
+            }
+        }
+        // Left
+        let mut x = point.x - 1;
+        loop {
+            if let Some(val2) = grid.get(&Point::new(x, point.y)) {
+                if val2 >= val1 {
+                    break;
+                }
+            }
+
+            if x > 0 {
+                x -= 1;
+            } else {
+                return true;
+            }
+        }
+        // Right
+        let mut x = point.x + 1;
+        loop {
+            if let Some(val2) = grid.get(&Point::new(x, point.y)) {
+                if val2 >= val1 {
+                    break;
+                }
+            }
+
+            if x < grid.width() {
+                x += 1;
+            } else {
+                return true;
+            }
+        }
+    }
+    trace!("{}, {} is not visible", point.x, point.y);
+    false
+}
+
+fn get_scenic_score(grid: &Grid<u32>, point: &Point<usize>) -> u32 {
+    let own_height = grid.get(point).unwrap();
+
+    let mut trees_up = 0;
+    if point.y > 0 {
+        for y in (0..=point.y - 1).rev() {
+            if let Some(tree_height) = grid.get(&Point::new(point.x, y)) {
+                trees_up += 1;
+                if tree_height >= own_height {
+                    break;
+                }
+            }
+        }
+    }
+    let mut trees_down = 0;
+    for y in point.y + 1..grid.height() {
+        if let Some(tree_height) = grid.get(&Point::new(point.x, y)) {
+            trees_down += 1;
+            if tree_height >= own_height {
+                break;
+            }
+        }
+    }
+    let mut trees_left = 0;
+    if point.x > 0 {
+        for x in (0..=point.x - 1).rev() {
+            if let Some(tree_height) = grid.get(&Point::new(x, point.y)) {
+                trees_left += 1;
+                if tree_height >= own_height {
+                    break;
+                }
+            }
+        }
+    }
+    let mut trees_right = 0;
+    for x in point.x + 1..grid.width() {
+        if let Some(tree_height) = grid.get(&Point::new(x, point.y)) {
+            trees_right += 1;
+            if tree_height >= own_height {
+                break;
+            }
+        }
+    }
+
+    trace!(
+        "{},{} = {}, {}, {}, {}",
+        point.x,
+        point.y,
+        trees_up,
+        trees_down,
+        trees_left,
+        trees_right
+    );
+    trees_up * trees_down * trees_left * trees_right
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u32>;
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Number of trees visible from outside the grid
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut visible_count = 0;
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                if is_visible(grid, &Point::new(x, y)) {
+                    visible_count += 1;
+                }
+            }
+        }
+        visible_count.into()
+    }
+
+    /// Highest scenic score of any tree
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut hi_score = 0;
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let score = get_scenic_score(grid, &Point::new(x, y));
+                if score > hi_score {
+                    hi_score = score;
+                }
+            }
+        }
+        hi_score.into()
+    }
+}
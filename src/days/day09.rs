@@ -0,0 +1,206 @@
+//! Day 9: Rope Bridge
+
+use crate::error::ParseError;
+use crate::geom::{hash, Direction, Point};
+use crate::log::{self, Level};
+use crate::parse::lines;
+use crate::solution::{Answer, Solution};
+use crate::{debug, trace};
+use std::collections::{HashMap, HashSet};
+
+/// Draw the rope in a grid for debugging
+fn draw_rope(rope: &[Point]) -> String {
+    struct Grid {
+        width: usize,
+        height: usize,
+        cells: HashMap<i32, char>,
+    }
+    let width = 15;
+    let height = 15;
+
+    let mut grid = Grid {
+        width,
+        height,
+        cells: HashMap::<i32, char>::new(),
+    };
+
+    for knot in rope {
+        let mut x = knot.x % width as i32;
+        if x < 0 {
+            x += width as i32;
+        }
+        let mut y = knot.y % height as i32;
+        if y < 0 {
+            y += height as i32;
+        }
+        grid.cells.entry(grid.width as i32 * y + x).or_insert('#');
+    }
+
+    let mut out = String::new();
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let key: i32 = grid.width as i32 * y as i32 + x as i32;
+            out.push(*grid.cells.get(&key).unwrap_or(&'.'));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<(Direction, i32)>;
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Number of positions visited by the tail of a two knot rope
+    fn part1(commands: &Self::Input) -> Answer {
+        let mut head_pos = Point::new(0, 0);
+        let mut head_route = vec![];
+        let mut tail_pos = Point::new(0, 0);
+        let mut tail_visited = HashSet::<u64>::new();
+        tail_visited.insert(hash(&tail_pos));
+
+        for &(dir, steps) in commands {
+            debug!("{:?} - {} steps", dir, steps);
+            for _ in 0..steps {
+                head_route.push(head_pos);
+                head_pos = head_pos.step(dir);
+
+                let distance = tail_pos.distance_to(&head_pos);
+                if distance > 1.5 {
+                    tail_pos = *head_route.last().unwrap();
+                    tail_visited.insert(hash(&tail_pos));
+                }
+                trace!(
+                    "Head=({},{}); Tail=({},{}); Distance={}",
+                    head_pos.x,
+                    head_pos.y,
+                    tail_pos.x,
+                    tail_pos.y,
+                    distance
+                );
+            }
+        }
+        tail_visited.len().into()
+    }
+
+    /// Number of positions visited by the tail of a ten knot rope
+    fn part2(commands: &Self::Input) -> Answer {
+        use std::io::IsTerminal;
+        use std::{thread, time};
+
+        // Animating the rope is only worth the wait when tracing this day on
+        // a terminal, where the frames are watched instead of stored
+        let animate = log::enabled(Level::Trace, module_path!()) && std::io::stderr().is_terminal();
+
+        let mut rope = Rope::new(10, Point::new(10, 10));
+
+        // The solution is to find the route that the last knot (tail) takes
+        let mut tail_route = HashSet::<u64>::new();
+
+        // Iterate over each command from input file
+        for &(dir, steps) in commands {
+            for _ in 0..steps {
+                // Debug print rope movement after every knot
+                rope.step_with(dir, |knots| {
+                    if animate {
+                        trace!("{}[2J\n{}", 27 as char, draw_rope(knots));
+                        thread::sleep(time::Duration::from_millis(10));
+                    }
+                });
+
+                // Mark tail position
+                tail_route.insert(hash(&rope.tail()));
+            }
+        }
+
+        tail_route.len().into()
+    }
+}
+
+/// Rope of knots where every knot follows the one before it
+#[derive(Debug, Clone)]
+pub struct Rope {
+    knots: Vec<Point>,
+}
+
+impl Rope {
+    /// Rope of `len` knots (at least one) all starting at `start`
+    pub fn new(len: usize, start: Point) -> Self {
+        Rope {
+            knots: vec![start; len.max(1)],
+        }
+    }
+
+    /// Move the head one step and pull the other knots after it
+    pub fn step(&mut self, dir: Direction) {
+        self.step_with(dir, |_| ());
+    }
+
+    /// Like [`Rope::step`], calling `moved` with the knots each time one of
+    /// the knots after the head has been pulled
+    pub fn step_with<F: FnMut(&[Point])>(&mut self, dir: Direction, mut moved: F) {
+        use std::cmp::Ordering;
+
+        // Move the head knot of the rope based on input instructions
+        self.knots[0] = self.knots[0].step(dir);
+
+        // Iterate over each knot in the rope and move each knot based
+        // on the previous knot position
+        for i in 1..self.knots.len() {
+            let prev = self.knots[i - 1];
+            let knot = &mut self.knots[i];
+
+            // If euclidean distance is greater than 1 square (includindg
+            // diagonal distance) move the next knot into the direction
+            // of the previous knot
+            if knot.distance_to(&prev) > 1.5 {
+                knot.x += match prev.x.cmp(&knot.x) {
+                    Ordering::Greater => 1,
+                    Ordering::Less => -1,
+                    _ => 0,
+                };
+
+                knot.y += match prev.y.cmp(&knot.y) {
+                    Ordering::Greater => 1,
+                    Ordering::Less => -1,
+                    _ => 0,
+                };
+            }
+            moved(&self.knots);
+        }
+    }
+
+    /// Positions of the knots from head to tail
+    pub fn knots(&self) -> &[Point] {
+        &self.knots
+    }
+
+    pub fn tail(&self) -> Point {
+        *self.knots.last().unwrap()
+    }
+}
+
+/// Parse the input as List of Directions and Steps
+fn parse_input(input: &str) -> Result<Vec<(Direction, i32)>, ParseError> {
+    let mut commands = vec![];
+    for line in lines(input) {
+        let dir = line.word(0, "direction")?;
+        let dir = match dir {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(line.error(dir, "`U`, `D`, `L` or `R`")),
+        };
+        let steps = line.number(line.word(1, "step count")?, "step count")?;
+        line.expect_words(2)?;
+        commands.push((dir, steps));
+    }
+    Ok(commands)
+}
@@ -0,0 +1,259 @@
+//! Day 10: Cathode-Ray Tube
+
+use crate::debug;
+use crate::error::ParseError;
+use crate::parse::lines;
+use crate::solution::{Answer, Solution};
+use std::fmt;
+
+///
+/// Instruction trait
+///
+trait Instruction {
+    fn name(&self) -> String;
+    fn next_step(&mut self, register: &mut i32);
+    fn is_ready(&self) -> bool;
+}
+
+impl fmt::Display for dyn Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+///
+/// Noop instruction
+///
+struct Noop {
+    executed: bool,
+}
+
+impl Noop {
+    fn new() -> Self {
+        Self { executed: false }
+    }
+}
+impl Instruction for Noop {
+    fn name(&self) -> String {
+        "noop".to_string()
+    }
+
+    fn next_step(&mut self, _: &mut i32) {
+        self.executed = true;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.executed
+    }
+}
+
+///
+/// Add Instrcution
+///
+struct Add {
+    cycle: usize,
+    value: i32,
+}
+impl Add {
+    fn new(value: i32) -> Self {
+        Add { cycle: 0, value }
+    }
+}
+
+impl Instruction for Add {
+    fn name(&self) -> String {
+        "add".to_string()
+    }
+
+    fn next_step(&mut self, register: &mut i32) {
+        if self.cycle == 1 {
+            *register += self.value;
+        }
+        self.cycle += 1;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cycle > 1
+    }
+}
+
+///
+/// CPU measuring the signal strength
+///
+struct SignalCpu {
+    x: i32,
+    instructions: Vec<Box<dyn Instruction>>,
+}
+
+impl SignalCpu {
+    fn new(instructions: Vec<Box<dyn Instruction>>) -> Self {
+        SignalCpu { x: 1, instructions }
+    }
+    /// The sum is taken in `i128`, where the strengths of any number of
+    /// cycles fit
+    fn run(&mut self) -> i128 {
+        let mut result = 0;
+
+        let mut i = 1;
+        for instruction in self.instructions.iter_mut() {
+            while !instruction.is_ready() {
+                if i != 40 && (i == 20 || (i + 20) % 40 == 0) {
+                    let signal_strength = i as i128 * self.x as i128;
+                    debug!(
+                        "#{}: SIGNAL STRENGTH ({} * {} = {})",
+                        i, i, self.x, signal_strength
+                    );
+                    result += signal_strength;
+                }
+
+                instruction.next_step(&mut self.x);
+                i += 1;
+            }
+        }
+        result
+    }
+}
+
+struct Crt {
+    width: usize,
+    cursor: usize,
+    pixels: Vec<bool>,
+}
+
+///
+/// CRT
+///
+impl Crt {
+    fn new(width: usize) -> Self {
+        Crt {
+            width,
+            cursor: 1,
+            pixels: vec![],
+        }
+    }
+
+    fn step(&mut self, pixel_pos: i32) {
+        let pixel_pos = pixel_pos as i64;
+        let pixel_range = (pixel_pos - 1)..=(pixel_pos + 1);
+        let lit = pixel_range.contains(&((self.cursor as i64 - 1) % self.width as i64));
+        self.pixels.push(lit);
+        self.cursor += 1;
+    }
+
+    /// Draw the lit pixels into a multiline string
+    fn draw(&self) -> String {
+        let mut screen = String::new();
+        for (i, pixel) in self.pixels.iter().enumerate() {
+            screen.push(if *pixel { '#' } else { ' ' });
+            if (i + 1) % self.width == 0 {
+                screen.push('\n');
+            }
+        }
+        screen
+    }
+}
+
+///
+/// CPU driving the CRT
+///
+struct Cpu {
+    x: i32,
+    crt: Crt,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Cpu {
+            x: 1,
+            crt: Crt::new(40),
+        }
+    }
+
+    /// Iterate over given set of instructions and run each cycle on every
+    /// instruction.
+    fn run(&mut self, instructions: &mut [Box<dyn Instruction>]) {
+        for instruction in instructions.iter_mut() {
+            while !instruction.is_ready() {
+                self.crt.step(self.x);
+                instruction.next_step(&mut self.x);
+            }
+        }
+    }
+}
+
+///
+/// Instruction as written in the input
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+impl Op {
+    /// Create a fresh executable instruction
+    fn instruction(&self) -> Box<dyn Instruction> {
+        match self {
+            Op::Noop => Box::new(Noop::new()),
+            Op::Addx(value) => Box::new(Add::new(*value)),
+        }
+    }
+}
+
+fn instructions(ops: &[Op]) -> Vec<Box<dyn Instruction>> {
+    ops.iter().map(|op| op.instruction()).collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Op>;
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ops(input)
+    }
+
+    /// Sum of the signal strengths during the 20th, 60th, 100th, ... cycles
+    fn part1(ops: &Self::Input) -> Answer {
+        let mut cpu = SignalCpu::new(instructions(ops));
+        Answer::Number(cpu.run())
+    }
+
+    /// Image drawn on the CRT
+    fn part2(ops: &Self::Input) -> Answer {
+        let mut cpu = Cpu::new();
+        cpu.run(&mut instructions(ops));
+        cpu.crt.draw().into()
+    }
+}
+
+/// Parse the instructions from the input
+///
+/// The register must stay within `i32` after every instruction, so that the
+/// CPUs never overflow it.
+fn parse_ops(input: &str) -> Result<Vec<Op>, ParseError> {
+    let mut ops = vec![];
+    let mut x = 1i64;
+    for line in lines(input) {
+        let instruction = line.word(0, "instruction")?;
+        match instruction {
+            "addx" => {
+                let value = line.word(1, "number")?;
+                line.expect_words(2)?;
+                let n: i32 = line.number(value, "number")?;
+                x += n as i64;
+                if i32::try_from(x).is_err() {
+                    return Err(line.error(value, "number keeping the register within i32"));
+                }
+                ops.push(Op::Addx(n));
+            }
+            "noop" => {
+                line.expect_words(1)?;
+                ops.push(Op::Noop);
+            }
+            _ => return Err(line.error(instruction, "`addx` or `noop`")),
+        }
+    }
+    Ok(ops)
+}
@@ -0,0 +1,404 @@
+//! Day 11: Monkey in the Middle
+
+use crate::error::ParseError;
+use crate::parse::{lines, unexpected_end, Line};
+use crate::repl::{self, Explorer};
+use crate::solution::{Answer, Part, Solution};
+use crate::{debug, trace};
+use core::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[derive(PartialEq, Clone)]
+enum Operation {
+    Add,
+    Multiply,
+    Power,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: u64,
+    items: VecDeque<u64>,
+    throw_to: (u64, u64),
+    test_num: u64,
+    operation: Operation,
+    operation_num: u64,
+    inspections: u64,
+}
+
+type MonkeyPtr = Rc<RefCell<Monkey>>;
+
+/// Parsed monkeys and the product of their test divisors
+pub struct Troop {
+    monkeys: HashMap<u64, Monkey>,
+    common_denominator: u64,
+}
+
+impl Troop {
+    /// Fresh copy of the monkeys that can be mutated while playing
+    fn monkey_ptrs(&self) -> HashMap<u64, MonkeyPtr> {
+        self.monkeys
+            .iter()
+            .map(|(id, monkey)| (*id, Rc::new(RefCell::new(monkey.clone()))))
+            .collect()
+    }
+}
+
+impl Monkey {
+    fn new() -> Self {
+        Monkey {
+            id: 0,
+            items: VecDeque::new(),
+            throw_to: (0, 0),
+            test_num: 0,
+            operation: Operation::Add,
+            operation_num: 0,
+            inspections: 0,
+        }
+    }
+}
+
+/// Play a single round where every monkey inspects and throws its items
+///
+/// `relief` is applied to the worry level after each inspection. The
+/// inspection is calculated in `u128`, which holds any sum, product or square
+/// of `u64` worry levels.
+fn play_round<F>(monkeys: &HashMap<u64, MonkeyPtr>, relief: F)
+where
+    F: Fn(u128) -> u64,
+{
+    for id in 0..monkeys.len() {
+        let mut monkey = monkeys.get(&(id as u64)).unwrap().borrow_mut();
+        while let Some(item) = monkey.items.pop_front() {
+            monkey.inspections += 1;
+            let (item, operation_num) = (item as u128, monkey.operation_num as u128);
+            let worry_level = relief(match monkey.operation {
+                Operation::Add => item + operation_num,
+                Operation::Multiply => item * operation_num,
+                Operation::Power => item.pow(operation_num as u32),
+            });
+            let throw_id = if worry_level.is_multiple_of(monkey.test_num) {
+                monkey.throw_to.0
+            } else {
+                monkey.throw_to.1
+            };
+            let mut target = monkeys.get(&throw_id).unwrap().borrow_mut();
+            target.items.push_back(worry_level);
+        }
+    }
+}
+
+/// Relief of part 1, saturating at the largest worry level
+fn divide_by_three(worry_level: u128) -> u64 {
+    u64::try_from(worry_level / 3).unwrap_or(u64::MAX)
+}
+
+/// Product of the inspection counts of the two most active monkeys, taken
+/// in `u128` where any two counts fit
+fn monkey_business(monkeys: &HashMap<u64, MonkeyPtr>) -> u128 {
+    let mut inspections = vec![];
+    for id in 0..monkeys.len() {
+        let monkey = monkeys.get(&(id as u64)).unwrap().borrow();
+        inspections.push(monkey.inspections);
+    }
+    inspections.sort_by(|a, b| b.cmp(a));
+    inspections[0] as u128 * inspections[1] as u128
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Troop;
+    const DAY: u8 = 11;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(input)
+    }
+
+    /// Monkey business after 20 rounds when worry level is divided by three
+    fn part1(troop: &Self::Input) -> Answer {
+        let monkeys = troop.monkey_ptrs();
+        for round in 1..=20 {
+            play_round(&monkeys, divide_by_three);
+
+            debug!("Round #{}", round);
+            for id in 0..monkeys.len() {
+                let monkey = monkeys.get(&(id as u64)).unwrap().borrow();
+                trace!("Monkey {}: {:?}", monkey.id, monkey.items);
+            }
+        }
+        monkey_business(&monkeys).into()
+    }
+
+    /// Monkey business after 10000 rounds without relief
+    fn part2(troop: &Self::Input) -> Answer {
+        let monkeys = troop.monkey_ptrs();
+        let common_denominator = troop.common_denominator;
+        for round in 1..=10000 {
+            play_round(&monkeys, |worry_level| {
+                (worry_level % common_denominator as u128) as u64
+            });
+
+            if round == 1 || round == 20 || round % 1000 == 0 {
+                debug!("== After round {} ==", round);
+                for id in 0..monkeys.len() {
+                    let monkey = monkeys.get(&(id as u64)).unwrap().borrow();
+                    trace!(
+                        "Monkey {} inspected items {} times",
+                        monkey.id,
+                        monkey.inspections
+                    );
+                }
+            }
+        }
+        monkey_business(&monkeys).into()
+    }
+}
+
+/// REPL state: the monkeys after `round` rounds
+struct Game {
+    troop: Troop,
+    monkeys: HashMap<u64, MonkeyPtr>,
+    round: usize,
+    /// Relief rule of the given part
+    part: Part,
+}
+
+impl Game {
+    fn reset(&mut self) {
+        self.monkeys = self.troop.monkey_ptrs();
+        self.round = 0;
+    }
+
+    /// Play until `round`, restarting if it is already past
+    fn play_until(&mut self, round: usize) {
+        if round < self.round {
+            self.reset();
+        }
+        let common_denominator = self.troop.common_denominator;
+        while self.round < round {
+            match self.part {
+                Part::One => play_round(&self.monkeys, divide_by_three),
+                Part::Two => play_round(&self.monkeys, |worry_level| {
+                    (worry_level % common_denominator as u128) as u64
+                }),
+            }
+            self.round += 1;
+        }
+    }
+
+    fn describe(&self) -> String {
+        let mut description = format!("after round {}:\n", self.round);
+        for id in 0..self.monkeys.len() {
+            let monkey = self.monkeys.get(&(id as u64)).unwrap().borrow();
+            let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+            description += &format!(
+                "Monkey {} (inspected items {} times):",
+                monkey.id, monkey.inspections
+            );
+            if !items.is_empty() {
+                description += &format!(" {}", items.join(", "));
+            }
+            description.push('\n');
+        }
+        description
+    }
+}
+
+/// Open the REPL explorer of the monkey game
+pub fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    let troop = parse_monkeys(input)?;
+    Ok(Box::new(Game {
+        monkeys: troop.monkey_ptrs(),
+        troop,
+        round: 0,
+        part: Part::One,
+    }))
+}
+
+impl Explorer for Game {
+    fn commands(&self) -> &'static [repl::Command] {
+        &[
+            repl::Command {
+                usage: "monkeys",
+                help: "items and inspection counts of the monkeys",
+            },
+            repl::Command {
+                usage: "round <n>",
+                help: "show the monkeys after round n",
+            },
+            repl::Command {
+                usage: "next [n]",
+                help: "play the next n rounds (default 1)",
+            },
+            repl::Command {
+                usage: "relief <1|2>",
+                help: "use the worry relief of part 1 or 2 and restart",
+            },
+            repl::Command {
+                usage: "business",
+                help: "monkey business so far",
+            },
+        ]
+    }
+
+    fn execute(&mut self, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "monkeys" => Ok(self.describe()),
+            "round" => {
+                self.play_until(repl::arg(args, 0, "round")?);
+                Ok(self.describe())
+            }
+            "next" => {
+                let rounds: usize = repl::optional_arg(args, 0, "round count")?.unwrap_or(1);
+                self.play_until(self.round.saturating_add(rounds));
+                Ok(self.describe())
+            }
+            "relief" => {
+                self.part = repl::arg(args, 0, "part")?;
+                self.reset();
+                Ok(self.describe())
+            }
+            _ => Ok(format!("{}\n", monkey_business(&self.monkeys))),
+        }
+    }
+}
+
+/// Parse the id of the monkey `monkey` throws to, which must be another one
+fn throw_target(line: &Line, monkey: &Monkey) -> Result<u64, ParseError> {
+    let target = line.last_number("monkey id")?;
+    if target == monkey.id {
+        let token = line.text.split_whitespace().last().unwrap_or_default();
+        return Err(line.error(token, "id of another monkey"));
+    }
+    Ok(target)
+}
+
+/// Parse the monkeys and the product of their test divisors
+///
+/// Monkey business needs at least two monkeys.
+fn parse_monkeys(input: &str) -> Result<Troop, ParseError> {
+    let mut monkeys = HashMap::<u64, Monkey>::new();
+
+    let mut common_denominator = 1;
+
+    // Throw targets are validated once all monkeys are known
+    let mut targets = vec![];
+
+    let mut monkey = Monkey::new();
+    // Whether the lines since the last blank line started with a header
+    let mut has_header = false;
+    for line in lines(input) {
+        // A blank line ends a monkey, repeated ones are skipped
+        if line.text.is_empty() {
+            if has_header {
+                let parsed = std::mem::replace(&mut monkey, Monkey::new());
+                monkeys.insert(parsed.id, parsed);
+                has_header = false;
+            }
+            continue;
+        }
+
+        // Parse Monkey ID
+        if line.text.starts_with("Monkey") {
+            if has_header {
+                return Err(line.error(line.text, "blank line before the next monkey"));
+            }
+            has_header = true;
+            let id = line.word(1, "monkey id")?;
+            let number = id
+                .strip_suffix(':')
+                .ok_or_else(|| line.error_at(line.text.len(), "`:`", "end of line"))?;
+            monkey.id = line.number(number, "monkey id")?;
+            if monkeys.contains_key(&monkey.id) {
+                return Err(line.error(number, "id of a new monkey"));
+            }
+            line.expect_words(2)?;
+        } else if !has_header {
+            return Err(line.error(line.text.trim_start(), "`Monkey <id>:` header"));
+        // Parse Item list
+        } else if line.text.contains("Starting items") {
+            let (_, items) = line
+                .text
+                .split_once(':')
+                .ok_or_else(|| line.error_at_end("`:`"))?;
+            for item in items.split(',') {
+                monkey
+                    .items
+                    .push_back(line.number(item.trim(), "worry level")?);
+            }
+        // Parse mathematical operation and the operation number
+        } else if line.text.contains("Operation") {
+            let operator = line.word(4, "`+` or `*`")?;
+            let operand = line.word(5, "number or `old`")?;
+            line.expect_words(6)?;
+            match (operator, operand) {
+                ("*", "old") => {
+                    monkey.operation = Operation::Power;
+                    monkey.operation_num = 2;
+                }
+                ("+", _) => {
+                    monkey.operation = Operation::Add;
+                    monkey.operation_num = line.number(operand, "number")?;
+                }
+                ("*", _) => {
+                    monkey.operation = Operation::Multiply;
+                    monkey.operation_num = line.number(operand, "number")?;
+                }
+                _ => return Err(line.error(operator, "`+` or `*`")),
+            }
+        // Parse Test logic (divisible by what number)
+        } else if line.text.contains("Test") {
+            monkey.test_num = line.last_number("divisor")?;
+            if monkey.test_num == 0 {
+                let divisor = line.word(3, "divisor")?;
+                return Err(line.error(divisor, "non-zero divisor"));
+            }
+            common_denominator = match u64::checked_mul(common_denominator, monkey.test_num) {
+                Some(product) => product,
+                None => {
+                    let divisor = line.word(3, "divisor")?;
+                    return Err(line.error(
+                        divisor,
+                        "divisor keeping the product of all divisors within u64",
+                    ));
+                }
+            };
+        // Parse `true` path
+        } else if line.text.contains("true") {
+            monkey.throw_to.0 = throw_target(&line, &monkey)?;
+            targets.push((line, monkey.throw_to.0));
+        // Parse `false` path
+        } else if line.text.contains("false") {
+            monkey.throw_to.1 = throw_target(&line, &monkey)?;
+            targets.push((line, monkey.throw_to.1));
+        } else {
+            return Err(line.error(line.text.trim_start(), "monkey description"));
+        }
+    }
+    if has_header {
+        monkeys.insert(monkey.id, monkey);
+    }
+
+    // Monkeys are played in id order so the ids must be 0..n
+    for (line, target) in targets {
+        if !monkeys.contains_key(&target) {
+            let token = line.text.split_whitespace().last().unwrap_or_default();
+            return Err(line.error(token, "existing monkey id"));
+        }
+    }
+    for id in 0..monkeys.len().max(2) as u64 {
+        match monkeys.get(&id) {
+            Some(monkey) if monkey.test_num != 0 => (),
+            Some(_) => return Err(unexpected_end(input, "`Test:` line of every monkey")),
+            None => return Err(unexpected_end(input, &format!("monkey {}", id))),
+        }
+    }
+
+    Ok(Troop {
+        monkeys,
+        common_denominator,
+    })
+}
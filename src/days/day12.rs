@@ -0,0 +1,140 @@
+//! Day 12: Hill Climbing Algorithm
+
+use crate::error::ParseError;
+use crate::geom::{bounding_box, dehash, hash, Point};
+use crate::parse::{lines, unexpected_end};
+use crate::pathfinding::shortest_path;
+use crate::solution::{Answer, Solution};
+use crate::{debug, trace};
+use std::collections::HashMap;
+
+/// Heightmap with start and end points
+pub struct HeightMap {
+    map: HashMap<u64, u32>,
+    start: Point,
+    end: Point,
+    /// All points at the lowest elevation (`a` and `S`)
+    lowest: Vec<Point>,
+}
+
+/// Draw function for graph map where key is hash of x,y coordinates
+/// and value tells the height in current position
+fn draw_graph_map(map: &HashMap<u64, u32>) -> String {
+    let Some((min, max)) = bounding_box(map.keys().map(|key| dehash(*key))) else {
+        return String::new();
+    };
+
+    let mut out = String::new();
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let key = hash(&Point::new(x, y));
+            match map.get(&key) {
+                Some(height) if *height >= 10 => out.push((65 + (*height - 10)) as u8 as char),
+                Some(height) => out += &height.to_string(),
+                None => out.push('.'),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse the input as graph map
+fn parse_graph_map(input: &str) -> Result<HeightMap, ParseError> {
+    let mut map: HashMap<u64, u32> = HashMap::new();
+    let mut start = None;
+    let mut end = None;
+    let mut lowest = vec![];
+
+    // Read the input one line at a time
+    for line in lines(input) {
+        let y = line.number - 1;
+        // Read line one character at a time
+        for (x, (offset, c)) in line.text.char_indices().enumerate() {
+            let point = Point::new(x as i32, y as i32);
+            let c = match &c {
+                'S' if start.is_none() => {
+                    start = Some(point);
+                    'a'
+                }
+                'E' if end.is_none() => {
+                    end = Some(point);
+                    'z'
+                }
+                'a'..='z' => c,
+                _ => return Err(line.error_at(offset, "elevation `a`-`z`", &c.to_string())),
+            };
+            if c == 'a' {
+                lowest.push(point);
+            }
+            let num = c as u32 - 97;
+            map.insert(hash(&point), num);
+        }
+    }
+    Ok(HeightMap {
+        map,
+        start: start.ok_or_else(|| unexpected_end(input, "start position `S`"))?,
+        end: end.ok_or_else(|| unexpected_end(input, "best signal position `E`"))?,
+        lowest,
+    })
+}
+
+/// Get neighbors of a point that are reachable with a single step
+/// (the destination can be at most one higher than the current height)
+fn get_neighbors(map: &HashMap<u64, u32>, point: Point) -> Vec<Point> {
+    let point_height = map[&hash(&point)];
+    point
+        .neighbors()
+        .into_iter()
+        .filter(|p| match map.get(&hash(p)) {
+            Some(height) => *height <= point_height + 1,
+            None => false,
+        })
+        .collect()
+}
+
+/// Answer of a part when the best signal cannot be reached
+pub const NO_PATH: &str = "no path";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+    const DAY: u8 = 12;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_graph_map(input)
+    }
+
+    /// Fewest steps from the start position to the best signal
+    fn part1(height_map: &Self::Input) -> Answer {
+        trace!("height map:\n{}", draw_graph_map(&height_map.map));
+        let shortest_path = shortest_path(&height_map.start, &height_map.end, |p| {
+            get_neighbors(&height_map.map, p)
+        });
+        match shortest_path {
+            Some(path) => path.steps().into(),
+            None => NO_PATH.into(),
+        }
+    }
+
+    /// Fewest steps from any square at the lowest elevation to the best signal
+    fn part2(height_map: &Self::Input) -> Answer {
+        trace!("height map:\n{}", draw_graph_map(&height_map.map));
+        let mut shortest = None;
+        for start in &height_map.lowest {
+            if let Some(shortest_path) = shortest_path(start, &height_map.end, |p| {
+                get_neighbors(&height_map.map, p)
+            }) {
+                if shortest.is_none_or(|steps| shortest_path.steps() < steps) {
+                    shortest = Some(shortest_path.steps());
+                    debug!("Path len = {}", shortest_path.steps());
+                }
+            }
+        }
+        match shortest {
+            Some(steps) => steps.into(),
+            None => NO_PATH.into(),
+        }
+    }
+}
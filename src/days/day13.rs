@@ -0,0 +1,297 @@
+//! Day 13: Distress Signal
+
+use crate::error::ParseError;
+use crate::parse::{lines, split_groups, Line};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use std::cmp::Ordering;
+use std::fmt;
+
+////////////////////////////////////////////////////////////////////////////////
+/// [`ElementList`]
+////////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementList(Vec<Element>);
+
+/// Order of two [`ElementList`]s is determined by following rules:
+/// 1) If both elements are numbers, they're in right order if left is smaller
+/// 2) If `left` list runs out of elements first, they're in right order
+/// 3) If `right` list runs out of elements first, they're in wrong orders
+/// 4) If both lists are equal length the order cannot be determined (`None`)
+impl Ord for ElementList {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in 0..self.0.len() {
+            let l = self.0.get(i).unwrap();
+            if let Some(r) = other.0.get(i) {
+                // If both elements are numbers, they're in right order if left is smaller
+                if l.is_number() && r.is_number() {
+                    match l.cmp(r) {
+                        // If Left is smaller (right order)
+                        Ordering::Less => return Ordering::Less,
+                        // If Right is smaller (wrong order)
+                        Ordering::Greater => return Ordering::Greater,
+                        // If equal, continue
+                        _ => (),
+                    }
+                } else {
+                    // Call recursively to check if the lists are in right order
+                    let l_list = l.convert_to_list();
+                    let r_list = r.convert_to_list();
+                    let result = l_list.cmp(&r_list);
+                    if result != Ordering::Equal {
+                        return result;
+                    }
+                }
+            } else {
+                // Right ran out of elements (wrong order)
+                return Ordering::Greater;
+            }
+        }
+        if self.0.len() < other.0.len() {
+            // Left ran out of elements (right order)
+            return Ordering::Less;
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for ElementList {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+/// [`Element`] represents a single element in a list
+////////////////////////////////////////////////////////////////////////////////
+#[derive(Clone)]
+pub enum Element {
+    Number(i32),
+    List(ElementList),
+}
+
+impl Element {
+    fn is_number(&self) -> bool {
+        matches!(self, Element::Number(_))
+    }
+
+    fn convert_to_list(&self) -> ElementList {
+        match self {
+            Element::List(list) => list.clone(),
+            Element::Number(number) => ElementList(vec![Element::Number(*number)]),
+        }
+    }
+}
+
+impl Eq for Element {}
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Element::Number(a), Element::Number(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Element::Number(a), Element::Number(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Debug for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Element::Number(n) => write!(f, "{}", n),
+            Element::List(l) => {
+                write!(f, "[")?;
+                for (i, e) in l.0.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", e)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Formats the packet as in the input, e.g. `[1,[2,3],[]]`
+impl fmt::Display for ElementList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            match e {
+                Element::Number(n) => write!(f, "{}", n)?,
+                Element::List(l) => write!(f, "{}", l)?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Parse the input as packet pairs separated by an empty line
+fn parse_packet_pairs(input: &str) -> Result<Vec<(ElementList, ElementList)>, ParseError> {
+    let lines: Vec<_> = lines(input).collect();
+    let mut element_pairs = vec![];
+    for group in split_groups(&lines) {
+        if group.is_empty() {
+            continue;
+        }
+        let mut packets = vec![];
+        for line in &group {
+            if packets.len() == 2 {
+                return Err(line.error_at(0, "empty line between pairs", line.text));
+            }
+            packets.push(parse_packet(line)?);
+        }
+        let right = packets.pop();
+        let left = packets.pop();
+        match (left, right) {
+            (Some(left), Some(right)) => element_pairs.push((left, right)),
+            _ => {
+                let line = group.last().map(|l| l.number + 1).unwrap_or(1);
+                return Err(ParseError::new(line, 1, "packet", "end of pair"));
+            }
+        }
+    }
+    Ok(element_pairs)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(ElementList, ElementList)>;
+    const DAY: u8 = 13;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_packet_pairs(input)
+    }
+
+    /// Sum of the indices of the pairs that are in the right order
+    fn part1(pairs: &Self::Input) -> Answer {
+        let mut num = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            trace!("{:?}", pair.0);
+            trace!("{:?}", pair.1);
+
+            match pair.0.cmp(&pair.1) {
+                Ordering::Less => num += i + 1,
+                // Equal packets are not in the right order either
+                Ordering::Greater | Ordering::Equal => (),
+            }
+        }
+        num.into()
+    }
+
+    /// Decoder key for the distress signal
+    fn part2(pairs: &Self::Input) -> Answer {
+        let mut packets = vec![];
+        for (left, right) in pairs {
+            packets.push(left.clone());
+            packets.push(right.clone());
+        }
+
+        // Add divider packets [[2]] and [[6]]
+        packets.push(ElementList(vec![Element::Number(2)]));
+        packets.push(ElementList(vec![Element::Number(6)]));
+
+        // Sort packets
+        packets.sort();
+
+        // Find the divider packet indices and calculate the decoder key
+        let mut divider_index1 = 0;
+        let mut divider_index2 = 0;
+        for (i, packet) in packets.iter().enumerate() {
+            if packet.0.len() == 1 {
+                if packet.0[0] == Element::Number(2) {
+                    divider_index1 = i + 1;
+                } else if packet.0[0] == Element::Number(6) {
+                    divider_index2 = i + 1;
+                }
+            }
+            trace!("{:?}", packet);
+        }
+        (divider_index1 * divider_index2).into()
+    }
+}
+
+/// Deepest nesting of lists accepted in a packet, which keeps the recursive
+/// parsing and comparison within the stack
+const MAX_DEPTH: usize = 256;
+
+/// Parse a single packet (bracketed list) spanning the whole line
+fn parse_packet(line: &Line) -> Result<ElementList, ParseError> {
+    if !line.text.starts_with('[') {
+        return Err(line.unexpected(0, "`[`"));
+    }
+    let mut pos = 1;
+    let packet = recursive_parse_elements(line, &mut pos, 1)?;
+    if pos < line.text.len() {
+        return Err(line.unexpected(pos, "end of line"));
+    }
+    Ok(packet)
+}
+
+/// Recursive function for parsing recursive list of elements from a string
+///
+/// `pos` points right after the opening `[` of a list at nesting `depth` and
+/// is advanced past the matching closing `]`.
+fn recursive_parse_elements(
+    line: &Line,
+    pos: &mut usize,
+    depth: usize,
+) -> Result<ElementList, ParseError> {
+    let bytes = line.text.as_bytes();
+    let mut elements = ElementList(vec![]);
+
+    // Empty list
+    if bytes.get(*pos) == Some(&b']') {
+        *pos += 1;
+        return Ok(elements);
+    }
+
+    loop {
+        match bytes.get(*pos) {
+            Some(b'[') if depth == MAX_DEPTH => {
+                let expected = format!("number, lists nested at most {} deep", MAX_DEPTH);
+                return Err(line.unexpected(*pos, &expected));
+            }
+            Some(b'[') => {
+                *pos += 1;
+                let list = recursive_parse_elements(line, pos, depth + 1)?;
+                elements.0.push(Element::List(list));
+            }
+            Some(b'0'..=b'9') => {
+                let start = *pos;
+                while bytes.get(*pos).is_some_and(|b| b.is_ascii_digit()) {
+                    *pos += 1;
+                }
+                let value = line.number(&line.text[start..*pos], "number")?;
+                elements.0.push(Element::Number(value));
+            }
+            _ => return Err(line.unexpected(*pos, "number or `[`")),
+        }
+        match bytes.get(*pos) {
+            Some(b',') => *pos += 1,
+            Some(b']') => {
+                *pos += 1;
+                return Ok(elements);
+            }
+            _ => return Err(line.unexpected(*pos, "`,` or `]`")),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Solutions for each day of the puzzle calendar
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+
+use crate::error::ParseError;
+use crate::solution::{self, Part, Report, Solution};
+
+/// Type erased runner that parses the input and solves the given parts
+pub type Runner = fn(&str, &[Part]) -> Result<Vec<Report>, ParseError>;
+
+/// Registry entry for the solution of a single day
+pub struct Day {
+    pub day: u8,
+    pub run: Runner,
+}
+
+impl Day {
+    /// Registry entry for solution `S`
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            run: solution::run::<S>,
+        }
+    }
+}
+
+/// Registry of all solved days in calendar order
+pub const DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+];
+
+/// Find the registry entry for given day
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,163 @@
+//! Error types shared by the whole crate
+
+use crate::json;
+use crate::submit::Refusal;
+use std::fmt;
+use std::io;
+
+/// Result type defaulting to the crate-wide [`Error`]
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error raised when the puzzle input does not match the expected format
+///
+/// Line and column numbers are 1-based. The file name is not known by the
+/// parsers themselves and is attached afterwards with [`ParseError::with_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// Attach the name of the file the input was read from
+    pub fn with_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    /// Render the error together with the offending line of `input`
+    ///
+    /// ```text
+    /// expected number, found `x`
+    ///  --> input/day5.txt:12:6
+    ///    |
+    /// 12 | move x from 1 to 2
+    ///    |      ^
+    /// ```
+    pub fn diagnostic(&self, input: &str) -> String {
+        let mut out = format!("{}\n", self.message());
+        let gutter = self.line.to_string().len();
+        out += &format!(
+            "{:gutter$}--> {}:{}:{}\n",
+            "",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+        );
+        if let Some(text) = input.lines().nth(self.line.saturating_sub(1)) {
+            out += &format!("{:gutter$} |\n", "");
+            out += &format!("{} | {}\n", self.line, text);
+            out += &format!("{:gutter$} | {:>col$}\n", "", "^", col = self.column);
+        }
+        out
+    }
+
+    /// The error as a JSON object
+    pub fn to_json(&self) -> json::Object {
+        let file = self
+            .file
+            .as_deref()
+            .map_or("null".to_string(), json::string);
+        json::Object::new()
+            .raw("file", file)
+            .value("line", self.line)
+            .value("column", self.column)
+            .string("expected", &self.expected)
+            .string("found", &self.found)
+            .string("message", &self.message())
+    }
+
+    /// Error message without the location
+    pub fn message(&self) -> String {
+        if self.found.is_empty() {
+            format!("expected {}", self.expected)
+        } else if self.found.starts_with("end of") {
+            format!("expected {}, found {}", self.expected, self.found)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.found)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Crate-wide error type
+#[derive(Debug)]
+pub enum Error {
+    /// Reading the puzzle input failed
+    Io { path: String, source: io::Error },
+    /// The puzzle input is malformed
+    Parse(ParseError),
+    /// Generating the files of a new day failed
+    Scaffold(String),
+    /// A request to the puzzle server failed
+    Http { url: String, reason: String },
+    /// An answer was not submitted
+    Refused(Refusal),
+}
+
+impl Error {
+    pub fn io(path: &str, source: io::Error) -> Self {
+        Error::Io {
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn http(url: &str, reason: &str) -> Self {
+        Error::Http {
+            url: url.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Scaffold(reason) => write!(f, "{}", reason),
+            Error::Http { url, reason } => write!(f, "{}: {}", url, reason),
+            Error::Refused(refusal) => write!(f, "not submitted: {}", refusal),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(e) => Some(e),
+            Error::Scaffold(_) | Error::Http { .. } | Error::Refused(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
@@ -0,0 +1,179 @@
+//! Puzzle inputs from a local cache, fetched from the puzzle server on a miss
+
+use crate::config;
+use crate::error::Error;
+use crate::http;
+use crate::input::{input_dir, read_input_file};
+use std::fs;
+use std::path::PathBuf;
+
+/// Year of the puzzle calendar
+pub const YEAR: u16 = 2022;
+
+/// Environment variable holding the session token of the puzzle server
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable overriding the URL of the puzzle server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Puzzle server used when neither [`BASE_URL_ENV`] nor the config sets one
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Source of puzzle inputs that are not cached yet
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Error>;
+}
+
+/// Fetches inputs over HTTP from `<base_url>/<year>/day/<day>/input`
+///
+/// The puzzle server identifies the user by the `session` cookie.
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Fetcher configured from `AOC_SESSION` and `AOC_BASE_URL` or the
+    /// config, if a session token is set
+    pub fn from_env() -> Option<Self> {
+        let session = std::env::var(SESSION_ENV).ok()?;
+        let base_url = std::env::var(BASE_URL_ENV)
+            .ok()
+            .or_else(|| config::get().base_url.clone())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Some(HttpFetcher::new(&base_url, &session))
+    }
+
+    /// Headers sent with every request
+    pub fn headers(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("Cookie", format!("session={}", self.session)),
+            (
+                "User-Agent",
+                format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+            ),
+        ]
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let headers = self.headers();
+        let headers: Vec<_> = headers.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let response = http::get(&url, &headers)?;
+        if !response.is_success() {
+            let reason = format!("server responded with status {}", response.status);
+            return Err(Error::http(&url, &reason));
+        }
+        Ok(response.body)
+    }
+}
+
+/// Inputs stored on disk as `<root>/<year>/day<day>.txt`
+///
+/// Inputs checked in directly under the root as `<root>/day<day>.txt` take
+/// precedence over the per-year files.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl InputCache {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        InputCache { root: root.into() }
+    }
+
+    /// Path where the input of given day is stored
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    /// Path of the cached input if there is one
+    pub fn get(&self, year: u16, day: u8) -> Option<PathBuf> {
+        let flat = self.root.join(format!("day{}.txt", day));
+        [flat, self.path(year, day)]
+            .into_iter()
+            .find(|path| path.is_file())
+    }
+
+    /// Store the input of given day and return its path
+    pub fn store(&self, year: u16, day: u8, input: &str) -> Result<PathBuf, Error> {
+        let path = self.path(year, day);
+        let io = |e| Error::io(&path.display().to_string(), e);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+        fs::write(&path, input).map_err(io)?;
+        Ok(path)
+    }
+}
+
+/// Looks up inputs in the cache and fetches the missing ones
+pub struct InputProvider {
+    cache: InputCache,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputProvider {
+    /// Provider that only reads the cache
+    pub fn new(cache: InputCache) -> Self {
+        InputProvider {
+            cache,
+            fetcher: None,
+        }
+    }
+
+    /// Fetch the inputs that are not cached with `fetcher`
+    pub fn with_fetcher<F: Fetcher + 'static>(mut self, fetcher: F) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Provider caching under the input directory, fetching over HTTP when a
+    /// session token is set
+    pub fn from_env() -> Self {
+        let provider = InputProvider::new(InputCache::new(input_dir()));
+        match HttpFetcher::from_env() {
+            Some(fetcher) => provider.with_fetcher(fetcher),
+            None => provider,
+        }
+    }
+
+    /// Path of the input of given day, fetching and caching it if needed
+    pub fn locate(&self, year: u16, day: u8) -> Result<PathBuf, Error> {
+        if let Some(path) = self.cache.get(year, day) {
+            return Ok(path);
+        }
+        match &self.fetcher {
+            Some(fetcher) => {
+                let input = fetcher.fetch(year, day)?;
+                self.cache.store(year, day, &input)
+            }
+            None => {
+                let path = self.cache.path(year, day);
+                let reason = format!("not cached and {} is not set", SESSION_ENV);
+                Err(Error::io(
+                    &path.display().to_string(),
+                    std::io::Error::new(std::io::ErrorKind::NotFound, reason),
+                ))
+            }
+        }
+    }
+
+    /// Input of given day, fetching and caching it if needed
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let path = self.locate(year, day)?;
+        read_input_file(&path).map_err(|e| Error::io(&path.display().to_string(), e))
+    }
+}
@@ -0,0 +1,85 @@
+//! Points and directions in 2D space
+
+/// Simple enum for the four main directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Point in 2D space
+///
+/// Signed coordinates are used by default; grids index with `Point<usize>`.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl Point {
+    pub const ZERO: Point = Point { x: 0, y: 0 };
+
+    /// Euclidean distance between two points
+    pub fn distance_to(&self, point: &Point) -> f32 {
+        f32::sqrt(((point.x - self.x).pow(2) + (point.y - self.y).pow(2)) as f32)
+    }
+
+    /// Point one step away into given direction (`Up` decreases `y`)
+    pub fn step(&self, dir: Direction) -> Point {
+        match dir {
+            Direction::Up => Point::new(self.x, self.y - 1),
+            Direction::Down => Point::new(self.x, self.y + 1),
+            Direction::Left => Point::new(self.x - 1, self.y),
+            Direction::Right => Point::new(self.x + 1, self.y),
+        }
+    }
+
+    /// The four orthogonal neighbors of the point
+    pub fn neighbors(&self) -> [Point; 4] {
+        [
+            Point::new(self.x - 1, self.y),
+            Point::new(self.x + 1, self.y),
+            Point::new(self.x, self.y - 1),
+            Point::new(self.x, self.y + 1),
+        ]
+    }
+}
+
+/// Function for hashing x,y coordinates into a single number
+pub fn hash(p: &Point) -> u64 {
+    ((p.x as u32 as u64) << 32) | (p.y as u32 as u64)
+}
+
+/// Function for dehashing x,y coordinates
+pub fn dehash(hash: u64) -> Point {
+    let x = (hash >> 32) as u32 as i32;
+    let y = (hash & 0xFFFFFFFF) as u32 as i32;
+    Point::new(x, y)
+}
+
+/// Find min and max x and y coordinates of given points
+///
+/// Returns `None` if there are no points.
+pub fn bounding_box<I>(points: I) -> Option<(Point, Point)>
+where
+    I: IntoIterator<Item = Point>,
+{
+    let mut iter = points.into_iter();
+    let first = iter.next()?;
+    let (mut min, mut max) = (first, first);
+    for p in iter {
+        min.x = min.x.min(p.x);
+        min.y = min.y.min(p.y);
+        max.x = max.x.max(p.x);
+        max.y = max.y.max(p.y);
+    }
+    Some((min, max))
+}
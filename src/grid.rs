@@ -0,0 +1,59 @@
+//! Fixed size two dimensional grid
+
+use crate::geom::Point;
+
+/// Rectangular grid of cells stored in row-major order
+#[derive(Debug, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T>
+where
+    T: Default + Copy,
+{
+    pub fn new(width: usize, height: usize) -> Self {
+        let cells = (0..width * height).map(|_| T::default()).collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn point_to_index(&self, point: &Point<usize>) -> Option<usize> {
+        if point.x >= self.width || point.y >= self.height {
+            return None;
+        }
+        Some(point.y * self.width + point.x)
+    }
+
+    /// Set the cell value and return the previous one (`None` if out of bounds)
+    pub fn set(&mut self, point: &Point<usize>, value: T) -> Option<T> {
+        if let Some(cell) = self.get_mut(point) {
+            let old_value = *cell;
+            *cell = value;
+            return Some(old_value);
+        }
+        None
+    }
+
+    pub fn get(&self, point: &Point<usize>) -> Option<&T> {
+        self.cells.get(self.point_to_index(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: &Point<usize>) -> Option<&mut T> {
+        let idx = self.point_to_index(point)?;
+        self.cells.get_mut(idx)
+    }
+}
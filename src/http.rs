@@ -0,0 +1,255 @@
+//! Minimal HTTP client for fetching inputs and submitting answers
+//!
+//! Plain `http://` URLs are served over a [`TcpStream`]. The standard library
+//! has no TLS, so `https://` requests are delegated to the `curl` command.
+
+use crate::error::Error;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Time allowed for connecting and for each read or write
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Status and body of a response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    /// Whether the status is 2xx
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Send a GET request
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, Error> {
+    request("GET", url, headers, None)
+}
+
+/// Send a POST request with an `application/x-www-form-urlencoded` body
+pub fn post_form(
+    url: &str,
+    headers: &[(&str, &str)],
+    form: &[(&str, &str)],
+) -> Result<Response, Error> {
+    let body = form
+        .iter()
+        .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+    request("POST", url, &headers, Some(&body))
+}
+
+/// Percent-encode a form value
+pub fn url_encode(s: &str) -> String {
+    let mut out = String::new();
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            b' ' => out.push('+'),
+            b => out += &format!("%{:02X}", b),
+        }
+    }
+    out
+}
+
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        plain_request(method, url, rest, headers, body)
+    } else if url.starts_with("https://") {
+        curl_request(method, url, headers, body)
+    } else {
+        Err(Error::http(
+            url,
+            "only http:// and https:// URLs are supported",
+        ))
+    }
+}
+
+/// Request over a plain TCP connection, `rest` is the URL without the scheme
+fn plain_request(
+    method: &str,
+    url: &str,
+    rest: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+    let io = |e: std::io::Error| Error::http(url, &e.to_string());
+
+    let mut stream = TcpStream::connect(&address).map_err(io)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(io)?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(io)?;
+
+    let mut head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        method, path, authority
+    );
+    for (name, value) in headers {
+        head += &format!("{}: {}\r\n", name, value);
+    }
+    let body = body.unwrap_or_default();
+    if method != "GET" {
+        head += &format!("Content-Length: {}\r\n", body.len());
+    }
+    head += "\r\n";
+    stream.write_all(head.as_bytes()).map_err(io)?;
+    stream.write_all(body.as_bytes()).map_err(io)?;
+
+    read_response(url, BufReader::new(stream))
+}
+
+/// Parse the status line, headers and body of a response
+fn read_response<R: BufRead>(url: &str, mut reader: R) -> Result<Response, Error> {
+    let io = |e: std::io::Error| Error::http(url, &e.to_string());
+    let invalid = || Error::http(url, "invalid HTTP response");
+
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(io)?;
+    let status = line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(invalid)?;
+
+    let mut length = None;
+    let mut chunked = false;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(io)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':').ok_or_else(invalid)?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            length = Some(value.parse::<usize>().map_err(|_| invalid())?);
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        }
+    }
+
+    let mut body = vec![];
+    if chunked {
+        loop {
+            line.clear();
+            reader.read_line(&mut line).map_err(io)?;
+            let size = line.trim_end().split(';').next().unwrap_or_default();
+            let size = usize::from_str_radix(size, 16).map_err(|_| invalid())?;
+            if size == 0 {
+                break;
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..]).map_err(io)?;
+            line.clear();
+            reader.read_line(&mut line).map_err(io)?;
+        }
+    } else if let Some(length) = length {
+        body.resize(length, 0);
+        reader.read_exact(&mut body).map_err(io)?;
+    } else {
+        reader.read_to_end(&mut body).map_err(io)?;
+    }
+
+    let body = String::from_utf8(body).map_err(|_| Error::http(url, "response is not UTF-8"))?;
+    Ok(Response { status, body })
+}
+
+/// Options of a `curl` config file setting the headers and body of a request
+///
+/// Passed on stdin with `--config -` so that the session cookie does not show
+/// up in the process list.
+pub fn curl_config(headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let quote = |value: &str| {
+        let mut quoted = String::from("\"");
+        for c in value.chars() {
+            match c {
+                '"' => quoted += "\\\"",
+                '\\' => quoted += "\\\\",
+                '\n' => quoted += "\\n",
+                '\r' => quoted += "\\r",
+                '\t' => quoted += "\\t",
+                c => quoted.push(c),
+            }
+        }
+        quoted + "\""
+    };
+    let mut config = String::new();
+    for (name, value) in headers {
+        config += &format!("header = {}\n", quote(&format!("{}: {}", name, value)));
+    }
+    if let Some(body) = body {
+        // Unlike `data-binary`, `data-raw` does not read files named by `@`
+        config += &format!("data-raw = {}\n", quote(body));
+    }
+    config
+}
+
+/// Request through the `curl` command, used for HTTPS
+fn curl_request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--max-time", "30"])
+        .args(["--request", method])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--config", "-"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let spawn_error = |e: std::io::Error| Error::http(url, &format!("cannot run curl: {}", e));
+    let mut child = command.spawn().map_err(spawn_error)?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(curl_config(headers, body).as_bytes())
+            .map_err(spawn_error)?;
+    }
+    let output = child.wait_with_output().map_err(spawn_error)?;
+    if !output.status.success() {
+        let reason = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::http(url, reason.trim()));
+    }
+
+    let out =
+        String::from_utf8(output.stdout).map_err(|_| Error::http(url, "response is not UTF-8"))?;
+    let (body, status) = out
+        .rsplit_once('\n')
+        .ok_or_else(|| Error::http(url, "invalid curl output"))?;
+    let status = status
+        .parse()
+        .map_err(|_| Error::http(url, "invalid curl output"))?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
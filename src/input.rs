@@ -0,0 +1,100 @@
+//! Reading puzzle input from files or standard input
+
+use crate::config;
+use crate::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory of the puzzle inputs
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Read the entire input file into a single string
+pub fn read_input_file<P: AsRef<Path>>(file_path: P) -> std::io::Result<String> {
+    let mut file = File::open(file_path)?;
+    let mut buf = String::new();
+    file.read_to_string(&mut buf)?;
+    Ok(buf)
+}
+
+/// Read the input file as a list of lines (line endings removed)
+pub fn read_lines_from_file<P: AsRef<Path>>(file_path: P) -> std::io::Result<Vec<String>> {
+    let file = File::open(file_path)?;
+    let reader = BufReader::new(file);
+    reader.lines().collect()
+}
+
+/// Directory holding the puzzle inputs
+///
+/// `AOC_INPUT_DIR` or else `input_dir` of the config overrides the default
+/// `input` directory. Without an override `input` is looked up from the
+/// working directory first and then from the directory of this crate, so the
+/// runner works from anywhere.
+pub fn input_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(INPUT_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = &config::get().input_dir {
+        return dir.clone();
+    }
+    let local = PathBuf::from("input");
+    if local.is_dir() {
+        return local;
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+}
+
+/// Default location of the puzzle input for given day
+pub fn default_input_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{}.txt", day))
+}
+
+/// Where the puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Standard input (`-` on the command line)
+    Stdin,
+    /// Explicit file path
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Source for a command line value where `-` stands for standard input
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Default source for given day (see [`default_input_path`])
+    pub fn for_day(day: u8) -> Self {
+        InputSource::File(default_input_path(day))
+    }
+
+    /// Read the whole input into a string
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut buf)
+                    .map_err(|e| Error::io(&self.to_string(), e))?;
+                Ok(buf)
+            }
+            InputSource::File(path) => {
+                read_input_file(path).map_err(|e| Error::io(&self.to_string(), e))
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Shared building blocks for the Advent of Code 2022 solutions.
+//!
+//! Each day lives in its own module under [`days`], implements the
+//! [`solution::Solution`] trait and is registered in [`days::DAYS`] so that
+//! the `aoc` runner can find it.
+
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+pub mod error;
+pub mod fetch;
+pub mod geom;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod memory;
+pub mod num;
+pub mod parse;
+pub mod pathfinding;
+pub mod pool;
+pub mod repl;
+pub mod scaffold;
+pub mod solution;
+pub mod stats;
+pub mod submit;
+pub mod table;
+pub mod toml;
+pub mod top;
+pub mod watch;
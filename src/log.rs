@@ -0,0 +1,220 @@
+//! Leveled logging of solver traces to stderr
+//!
+//! Solvers log through the [`error!`](crate::error!), [`warn!`](crate::warn!),
+//! [`info!`](crate::info!), [`debug!`](crate::debug!) and
+//! [`trace!`](crate::trace!) macros. The module path of the call site is the
+//! target of the message, so each day can be enabled separately:
+//!
+//! ```text
+//! debug              debug messages of every day
+//! day05=trace        trace messages of day 5, default level for the rest
+//! warn,day13=debug   debug messages of day 13, warnings for the rest
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+/// Environment variable holding the default filter of the `aoc` runner
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Severity of a message, from the most to the least important
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// Level one step more verbose, saturating at [`Level::Trace`]
+    pub fn more_verbose(self) -> Self {
+        match self {
+            Level::Off => Level::Error,
+            Level::Error => Level::Warn,
+            Level::Warn => Level::Info,
+            Level::Info => Level::Debug,
+            Level::Debug | Level::Trace => Level::Trace,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Level::Off,
+            1 => Level::Error,
+            2 => Level::Warn,
+            3 => Level::Info,
+            4 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Level::Off),
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("invalid log level `{}`", s)),
+        }
+    }
+}
+
+/// Maximum level of messages per target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// Level of targets without a directive
+    pub default: Level,
+    /// Target and level pairs, the last matching one wins
+    directives: Vec<(String, Level)>,
+}
+
+impl Filter {
+    /// Filter passing messages up to `level` from every target
+    pub const fn new(level: Level) -> Self {
+        Filter {
+            default: level,
+            directives: Vec::new(),
+        }
+    }
+
+    /// Parse comma separated `level`, `target` and `target=level` directives
+    ///
+    /// A target without a level enables all of its messages.
+    pub fn parse(spec: &str, default: Level) -> Result<Self, String> {
+        let mut filter = Filter::new(default);
+        filter.add(spec)?;
+        Ok(filter)
+    }
+
+    /// Add the directives of `spec` on top of the current ones
+    pub fn add(&mut self, spec: &str) -> Result<(), String> {
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => self.directives.push((target.to_string(), level.parse()?)),
+                None => match directive.parse() {
+                    Ok(level) => self.default = level,
+                    Err(_) => self.directives.push((directive.to_string(), Level::Trace)),
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Most verbose level passed for any target
+    pub fn max_level(&self) -> Level {
+        self.directives
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+
+    /// Whether messages of `level` from `target` pass the filter
+    ///
+    /// A directive matches a target equal to it, a target starting with it
+    /// followed by `::` or a target whose last path segment equals it, so
+    /// `day05` matches `advent_of_code_2022::days::day05`.
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let max = self
+            .directives
+            .iter()
+            .rev()
+            .find(|(directive, _)| matches_target(directive, target))
+            .map_or(self.default, |(_, level)| *level);
+        level != Level::Off && level <= max
+    }
+}
+
+fn matches_target(directive: &str, target: &str) -> bool {
+    target == directive
+        || target
+            .strip_prefix(directive)
+            .is_some_and(|rest| rest.starts_with("::"))
+        || target.rsplit("::").next() == Some(directive)
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Level::Warn));
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Install the filter used by the logging macros
+pub fn init(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap() = filter;
+}
+
+/// Whether a message of `level` from `target` would be logged
+pub fn enabled(level: Level, target: &str) -> bool {
+    level <= Level::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+        && FILTER.read().unwrap().enabled(level, target)
+}
+
+/// Write a message to stderr, used by the logging macros
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    let target = target.rsplit("::").next().unwrap_or(target);
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+/// Log a message at the given level
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Log an error message
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+/// Log a warning
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+/// Log an informational message
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Log a debug message
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Log a detailed trace message
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
@@ -0,0 +1,156 @@
+//! Heap usage of the solutions, counted by a wrapper of the system allocator
+//!
+//! A binary opts in by installing [`CountingAllocator`] as its global
+//! allocator. Allocations are only counted inside [`measure`], so the
+//! allocator costs one atomic load per allocation the rest of the time.
+
+use crate::json;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+/// Bytes in use relative to the start of the measurement, negative when
+/// memory allocated before it is freed
+static CURRENT: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// Serializes measurements, the counters are shared by all threads
+static MEASURING: Mutex<()> = Mutex::new(());
+
+/// System allocator that counts allocations during [`measure`]
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(size as u64, Ordering::Relaxed);
+            let current = CURRENT.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+
+    fn freed(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            CURRENT.fetch_sub(size as i64, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::freed(layout.size());
+    }
+
+    /// Counted as a new allocation of the new size replacing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+        new
+    }
+}
+
+/// Heap usage of a measured piece of code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of allocations, including reallocations
+    pub allocations: u64,
+    /// Total bytes allocated
+    pub bytes: u64,
+    /// Highest number of bytes in use at the same time
+    pub peak: u64,
+}
+
+impl Usage {
+    pub fn to_json(&self) -> json::Object {
+        json::Object::new()
+            .value("allocations", self.allocations)
+            .value("bytes", self.bytes)
+            .value("peak_bytes", self.peak)
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Run `f` and count the allocations it makes
+///
+/// Allocations of other threads running at the same time are counted too.
+/// Without [`CountingAllocator`] installed the usage is all zeros.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let _guard = MEASURING.lock().unwrap_or_else(|e| e.into_inner());
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+
+    COUNTING.store(true, Ordering::SeqCst);
+    let result = f();
+    COUNTING.store(false, Ordering::SeqCst);
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Whether [`CountingAllocator`] is the global allocator
+pub fn is_counting() -> bool {
+    let (_, usage) = measure(|| std::hint::black_box(Box::new(0u64)));
+    usage.allocations > 0
+}
+
+/// Human readable byte count with a binary unit matching its magnitude
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
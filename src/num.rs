@@ -0,0 +1,163 @@
+//! Numeric types that totals can be accumulated in without overflowing
+//! silently
+//!
+//! [`Total`] is implemented for the primitive integers, which report an
+//! overflow, and for [`BigUint`], which never overflows.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Number with checked addition
+pub trait Total: Clone + Default + Ord + fmt::Display + FromStr {
+    /// Short name of the type used in errors
+    const NAME: &'static str;
+
+    /// Sum of `self` and `other`, `None` if it is not representable
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Nearest floating point value
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_total {
+    ($($t:ty),*) => {
+        $(
+            impl Total for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_total!(i32, i64, u32, u64, i128, u128);
+
+/// Each limb holds nine decimal digits
+const LIMB: u32 = 1_000_000_000;
+
+/// Unsigned integer of arbitrary size
+///
+/// Only what totals need is supported: parsing decimal digits, addition,
+/// comparison and display.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 10^9 digits, least significant first, without leading zeros
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    fn normalize(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(mut value: u64) -> Self {
+        let mut limbs = vec![];
+        while value > 0 {
+            limbs.push((value % LIMB as u64) as u32);
+            value /= LIMB as u64;
+        }
+        BigUint { limbs }
+    }
+}
+
+impl std::ops::Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = self.limbs.get(i).unwrap_or(&0) + other.limbs.get(i).unwrap_or(&0) + carry;
+            limbs.push(sum % LIMB);
+            carry = sum / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Error for text that is not a non-empty string of decimal digits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigUintError;
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid digit found in string")
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigUintError);
+        }
+        // Digits are grouped by nine from the least significant one
+        let limbs = digits
+            .as_bytes()
+            .rchunks(9)
+            .map(|chunk| chunk.iter().fold(0, |n, b| n * 10 + (b - b'0') as u32))
+            .collect();
+        Ok(BigUint { limbs }.normalize())
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((most, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        let mut digits = most.to_string();
+        for limb in rest.iter().rev() {
+            digits += &format!("{:09}", limb);
+        }
+        f.pad(&digits)
+    }
+}
+
+impl Total for BigUint {
+    const NAME: &'static str = "BigUint";
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.limbs
+            .iter()
+            .rev()
+            .fold(0.0, |value, &limb| value * LIMB as f64 + limb as f64)
+    }
+}
@@ -0,0 +1,240 @@
+//! Small helpers shared by the input parsers
+//!
+//! The helpers keep track of line numbers and columns so that malformed input
+//! is reported as a [`ParseError`] pointing at the offending token.
+
+use crate::error::ParseError;
+use crate::geom::Point;
+use crate::grid::Grid;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Single line of input together with its 1-based line number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl AsRef<str> for Line<'_> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+impl<'a> Line<'a> {
+    /// Byte offset of `token` within the line
+    ///
+    /// `token` should be a subslice of the line; anything else is reported
+    /// at the end of the line.
+    fn offset_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let ptr = token.as_ptr() as usize;
+        if ptr >= start && ptr + token.len() <= start + self.text.len() {
+            ptr - start
+        } else {
+            self.text.len()
+        }
+    }
+
+    /// Error pointing at the given byte offset of the line
+    pub fn error_at(&self, offset: usize, expected: &str, found: &str) -> ParseError {
+        let column = self
+            .text
+            .get(..offset)
+            .map(|s| s.chars().count())
+            .unwrap_or(0)
+            + 1;
+        ParseError::new(self.number, column, expected, found)
+    }
+
+    /// Error pointing at `token`, which must be a subslice of the line
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        let found = if token.is_empty() {
+            "end of line"
+        } else {
+            token
+        };
+        self.error_at(self.offset_of(token), expected, found)
+    }
+
+    /// Error pointing at the character at given byte offset (or end of line)
+    pub fn unexpected(&self, offset: usize, expected: &str) -> ParseError {
+        match self.text.get(offset..).and_then(|rest| rest.chars().next()) {
+            Some(c) => self.error_at(offset, expected, &c.to_string()),
+            None => self.error_at_end(expected),
+        }
+    }
+
+    /// Error pointing at the end of the line
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        self.error_at(self.text.len(), expected, "end of line")
+    }
+
+    /// Whitespace separated words of the line
+    pub fn words(&self) -> Vec<&'a str> {
+        self.text.split_whitespace().collect()
+    }
+
+    /// The `index`th whitespace separated word of the line
+    pub fn word(&self, index: usize, expected: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .split_whitespace()
+            .nth(index)
+            .ok_or_else(|| self.error_at_end(expected))
+    }
+
+    /// Parse `token` (a subslice of the line) as a number
+    pub fn number<T: FromStr>(&self, token: &'a str, expected: &str) -> Result<T, ParseError> {
+        token.parse::<T>().map_err(|_| self.error(token, expected))
+    }
+
+    /// Parse the last whitespace separated word of the line as a number
+    pub fn last_number<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        match self.text.split_whitespace().last() {
+            Some(token) => self.number(token, expected),
+            None => Err(self.error_at_end(expected)),
+        }
+    }
+
+    /// Check that there is nothing after the `count` first words
+    pub fn expect_words(&self, count: usize) -> Result<(), ParseError> {
+        match self.text.split_whitespace().nth(count) {
+            Some(token) => Err(self.error(token, "end of line")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Iterate over the lines of the input with 1-based line numbers
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+/// Error for input that ended while more was expected
+pub fn unexpected_end(input: &str, expected: &str) -> ParseError {
+    ParseError::new(input.lines().count() + 1, 1, expected, "end of input")
+}
+
+/// Split lines into groups separated by empty lines
+///
+/// Empty groups (e.g. caused by consecutive empty lines) are preserved so
+/// that the group count matches the input layout.
+pub fn split_groups<S: AsRef<str> + Clone>(lines: &[S]) -> Vec<Vec<S>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for line in lines {
+        if line.as_ref().is_empty() {
+            groups.push(group);
+            group = vec![];
+        } else {
+            group.push(line.clone());
+        }
+    }
+    groups.push(group);
+    groups
+}
+
+/// Group of consecutive non-empty lines read by [`read_groups`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Group {
+    /// 1-based line number of the first line of the group
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Group {
+    /// Lines of the group with their line numbers in the whole input
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.lines.iter().enumerate().map(|(i, text)| Line {
+            number: self.first_line + i,
+            text,
+        })
+    }
+}
+
+/// Iterator over the groups of a reader, see [`read_groups`]
+#[derive(Debug)]
+pub struct Groups<R> {
+    reader: R,
+    buf: String,
+    /// Number of the next line to read
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Iterator for Groups<R> {
+    type Item = io::Result<Group>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut group = Group {
+            first_line: self.line,
+            lines: vec![],
+        };
+        loop {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => {
+                    self.done = true;
+                    return Some(Ok(group));
+                }
+                Ok(_) => (),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+            self.line += 1;
+            let text = self.buf.strip_suffix('\n').unwrap_or(&self.buf);
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            if text.is_empty() {
+                return Some(Ok(group));
+            }
+            group.lines.push(text.to_string());
+        }
+    }
+}
+
+/// Stream the groups separated by empty lines from `reader`
+///
+/// The groups are the same as the ones of [`split_groups`], but only one
+/// group is held in memory at a time.
+pub fn read_groups<R: BufRead>(reader: R) -> Groups<R> {
+    Groups {
+        reader,
+        buf: String::new(),
+        line: 1,
+        done: false,
+    }
+}
+
+/// Parse lines of single digits (e.g. tree heights) into a grid
+///
+/// All lines must be equally long.
+pub fn digit_grid(lines: &[Line]) -> Result<Grid<u32>, ParseError> {
+    let width = lines.first().map(|l| l.text.chars().count()).unwrap_or(0);
+    let mut grid = Grid::<u32>::new(width, lines.len());
+    for (y, line) in lines.iter().enumerate() {
+        let mut x = 0;
+        for (offset, c) in line.text.char_indices() {
+            if x >= width {
+                return Err(line.error_at(offset, "end of line", &c.to_string()));
+            }
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| line.error_at(offset, "digit", &c.to_string()))?;
+            grid.set(&Point::new(x, y), digit);
+            x += 1;
+        }
+        if x < width {
+            return Err(line.error_at_end("digit"));
+        }
+    }
+    Ok(grid)
+}
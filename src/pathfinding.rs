@@ -0,0 +1,63 @@
+//! Shortest path search on a 2D point graph
+
+use crate::geom::{hash, Point};
+use std::collections::{BinaryHeap, HashSet};
+
+/// Wrapper struct for holding walked path
+///
+/// Paths are ordered by length in reverse so that [`BinaryHeap`] pops the
+/// shortest path first.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Path {
+    pub path: Vec<Point>,
+}
+
+impl Path {
+    /// Number of steps taken (the start point is not a step)
+    pub fn steps(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+}
+
+impl PartialOrd for Path {
+    fn partial_cmp(&self, other: &Path) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.path.len().cmp(&self.path.len())
+    }
+}
+
+/// Find shortest path between two points using dijkstra algorithm
+///
+/// `neighbors` returns the points that can be reached from given point with
+/// a single step.
+pub fn shortest_path<F>(start: &Point, end: &Point, mut neighbors: F) -> Option<Path>
+where
+    F: FnMut(Point) -> Vec<Point>,
+{
+    let mut visited: HashSet<u64> = HashSet::new();
+    let mut heap: BinaryHeap<Path> = BinaryHeap::new();
+    heap.push(Path { path: vec![*start] });
+    while let Some(path) = heap.pop() {
+        let point = *path.path.last()?;
+        let path = path.path;
+        if point == *end {
+            return Some(Path { path });
+        }
+        if !visited.insert(hash(&point)) {
+            continue;
+        }
+        for neighbor in neighbors(point) {
+            if !visited.contains(&hash(&neighbor)) {
+                let mut new_path = path.clone();
+                new_path.push(neighbor);
+                heap.push(Path { path: new_path });
+            }
+        }
+    }
+    None
+}
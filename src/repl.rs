@@ -0,0 +1,193 @@
+//! Interactive exploration of the parsed puzzle state for `aoc repl`
+//!
+//! Every day can be solved from the REPL. Days that register an
+//! [`Explorer`] in [`EXPLORERS`] offer additional commands for stepping
+//! through their state, built on the same parser as the solution.
+
+use crate::days::{day05, day07, day11, Day};
+use crate::error::ParseError;
+use crate::pool::panic_message;
+use crate::solution::Part;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+
+/// Usage line and description of a REPL command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command {
+    pub usage: &'static str,
+    pub help: &'static str,
+}
+
+impl Command {
+    /// Name of the command, the first word of its usage
+    pub fn name(&self) -> &'static str {
+        self.usage.split_whitespace().next().unwrap_or_default()
+    }
+}
+
+/// Commands available for every day
+const BUILTIN: &[Command] = &[
+    Command {
+        usage: "solve [1|2]",
+        help: "solve both parts or the given part",
+    },
+    Command {
+        usage: "help",
+        help: "list the commands",
+    },
+    Command {
+        usage: "quit",
+        help: "leave the REPL (also `exit` or end of input)",
+    },
+];
+
+/// Day specific state that can be inspected and stepped through
+pub trait Explorer {
+    /// Commands understood by [`Explorer::execute`]
+    fn commands(&self) -> &'static [Command];
+
+    /// Run one of the [`Explorer::commands`] and return its output
+    fn execute(&mut self, name: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// Creates the explorer of a day from its puzzle input
+pub type Opener = fn(&str) -> Result<Box<dyn Explorer>, ParseError>;
+
+/// Registry of the days with an [`Explorer`]
+pub const EXPLORERS: &[(u8, Opener)] = &[
+    (5, day05::explore),
+    (7, day07::explore),
+    (11, day11::explore),
+];
+
+/// Argument `index` of a command, `None` if it was not given
+pub fn optional_arg<T: FromStr>(
+    args: &[&str],
+    index: usize,
+    what: &str,
+) -> Result<Option<T>, String> {
+    args.get(index)
+        .map(|arg| {
+            arg.parse()
+                .map_err(|_| format!("invalid {} `{}`", what, arg))
+        })
+        .transpose()
+}
+
+/// Argument `index` of a command
+pub fn arg<T: FromStr>(args: &[&str], index: usize, what: &str) -> Result<T, String> {
+    optional_arg(args, index, what)?.ok_or_else(|| format!("missing {}", what))
+}
+
+/// Result of evaluating a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// Output of a command, empty for a blank line
+    Output(String),
+    Quit,
+}
+
+/// REPL state of a single day and its input
+pub struct Session {
+    day: &'static Day,
+    input: String,
+    explorer: Option<Box<dyn Explorer>>,
+}
+
+impl Session {
+    /// Parse `input` of `day` and open its explorer if it has one
+    pub fn open(day: &'static Day, input: String) -> Result<Self, ParseError> {
+        // Solving no parts only parses, so errors are reported up front
+        (day.run)(&input, &[])?;
+        let explorer = Self::explorer(day.day, &input)?;
+        Ok(Session {
+            day,
+            input,
+            explorer,
+        })
+    }
+
+    fn explorer(day: u8, input: &str) -> Result<Option<Box<dyn Explorer>>, ParseError> {
+        EXPLORERS
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|(_, open)| open(input))
+            .transpose()
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day.day
+    }
+
+    /// Commands of the day followed by the ones of every day
+    pub fn commands(&self) -> Vec<Command> {
+        let own = self.explorer.as_ref().map_or(&[][..], |e| e.commands());
+        own.iter().chain(BUILTIN).copied().collect()
+    }
+
+    /// Evaluate one line of input
+    ///
+    /// A command that panics, e.g. on an arithmetic overflow after too many
+    /// steps, is reported as an error. The explorer is opened again then,
+    /// since its state may have been left half updated.
+    pub fn eval(&mut self, line: &str) -> Result<Reply, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, args)) = words.split_first() else {
+            return Ok(Reply::Output(String::new()));
+        };
+        let output = match name {
+            "quit" | "exit" => return Ok(Reply::Quit),
+            "help" => self.help(),
+            "solve" => self.solve(args)?,
+            _ => {
+                let explorer = self
+                    .explorer
+                    .as_mut()
+                    .filter(|e| e.commands().iter().any(|c| c.name() == name))
+                    .ok_or_else(|| format!("unknown command `{}`, try `help`", name))?;
+                match panic::catch_unwind(AssertUnwindSafe(|| explorer.execute(name, args))) {
+                    Ok(output) => output?,
+                    Err(payload) => {
+                        self.explorer = Self::explorer(self.day.day, &self.input)
+                            .map_err(|e| e.diagnostic(&self.input))?;
+                        return Err(format!(
+                            "`{}` panicked: {}, the state was reset",
+                            name,
+                            panic_message(payload.as_ref())
+                        ));
+                    }
+                }
+            }
+        };
+        Ok(Reply::Output(output))
+    }
+
+    fn help(&self) -> String {
+        let commands = self.commands();
+        let width = commands.iter().map(|c| c.usage.len()).max().unwrap_or(0);
+        let mut help = String::new();
+        for command in commands {
+            let _ = writeln!(help, "{:width$}  {}", command.usage, command.help);
+        }
+        help
+    }
+
+    fn solve(&self, args: &[&str]) -> Result<String, String> {
+        let parts = match optional_arg::<Part>(args, 0, "part")? {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        };
+        let reports = (self.day.run)(&self.input, &parts).map_err(|e| e.diagnostic(&self.input))?;
+        let mut output = String::new();
+        for report in reports {
+            let answer = report.answer.to_string();
+            if answer.contains('\n') {
+                let _ = writeln!(output, "Part {}:\n{}", report.part, answer.trim_end());
+            } else {
+                let _ = writeln!(output, "Part {}: {}", report.part, answer);
+            }
+        }
+        Ok(output)
+    }
+}
@@ -0,0 +1,181 @@
+//! Generator for the files of a new day, used by `aoc new`
+
+use crate::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Solution module of a new day following the layout of the existing days
+fn module_template(day: u8, title: &str) -> String {
+    format!(
+        r#"//! Day {day}: {title}
+
+use crate::error::ParseError;
+use crate::parse::lines;
+use crate::solution::{{Answer, Solution}};
+
+/// Parse the input as list of lines
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {{
+    let mut rows = vec![];
+    for line in lines(input) {{
+        rows.push(line.text.to_string());
+    }}
+    Ok(rows)
+}}
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+    const DAY: u8 = {day};
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse_input(input)
+    }}
+
+    fn part1(rows: &Self::Input) -> Answer {{
+        rows.len().into()
+    }}
+
+    fn part2(rows: &Self::Input) -> Answer {{
+        rows.len().into()
+    }}
+}}
+"#
+    )
+}
+
+/// Example test that stays ignored until the answers are filled in
+fn test_template(day: u8) -> String {
+    format!(
+        r#"
+example!(
+    #[ignore = "fill in the answers of the example"]
+    day{day:02}_example,
+    day{day:02}::Day{day:02},
+    "day{day}.txt",
+    0,
+    0
+);
+"#
+    )
+}
+
+/// Fuzz target feeding arbitrary text to the parser of the day
+fn fuzz_target_template(day: u8) -> String {
+    format!(
+        r#"#![no_main]
+
+use advent_of_code_2022::days::day{day:02}::Day{day:02};
+use advent_of_code_2022::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {{
+    let _ = Day{day:02}::parse(input);
+}});
+"#
+    )
+}
+
+/// Binary entry of the fuzz target in `fuzz/Cargo.toml`
+fn fuzz_bin_template(day: u8) -> String {
+    format!(
+        r#"
+[[bin]]
+name = "day{day:02}"
+path = "fuzz_targets/day{day:02}.rs"
+test = false
+doc = false
+bench = false
+"#
+    )
+}
+
+/// Insert `line` into the run of lines starting with `prefix` followed by a
+/// two digit day, keeping the days in order
+fn insert_sorted(source: &str, prefix: &str, day: u8, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let rest = l.strip_prefix(prefix)?;
+            Some((i, rest.get(..2)?.parse().ok()?))
+        })
+        .collect();
+    let index = match days.iter().find(|(_, d)| *d > day) {
+        Some((i, _)) => *i,
+        None => days.last()?.0 + 1,
+    };
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Create the files of a new day under the crate at `root`
+///
+/// Generates `src/days/dayNN.rs`, registers it in `src/days/mod.rs`, creates
+/// an empty example fixture and appends an ignored test to `tests/days.rs`.
+/// If the crate has a `fuzz` directory, a fuzz target of the parser is added
+/// too. Returns the created and modified files. Nothing is written if the day
+/// already exists.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Scaffold(format!("day {} is not in 1..=25", day)));
+    }
+    let module = root.join(format!("src/days/day{:02}.rs", day));
+    if module.exists() {
+        return Err(Error::Scaffold(format!(
+            "day {} already exists: {}",
+            day,
+            module.display()
+        )));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| Error::io(&path.display().to_string(), e))
+    };
+    let registry = root.join("src/days/mod.rs");
+    let source = read(&registry)?;
+    let source = insert_sorted(
+        &source,
+        "pub mod day",
+        day,
+        &format!("pub mod day{:02};", day),
+    )
+    .and_then(|s| {
+        insert_sorted(
+            &s,
+            "    Day::of::<day",
+            day,
+            &format!("    Day::of::<day{0:02}::Day{0:02}>(),", day),
+        )
+    })
+    .ok_or_else(|| Error::Scaffold(format!("no day registry found in {}", registry.display())))?;
+
+    let tests = root.join("tests/days.rs");
+    let test_source = read(&tests)? + &test_template(day);
+    let example = root.join(format!("input/examples/day{}.txt", day));
+    let fuzz_manifest = root.join("fuzz/Cargo.toml");
+    let fuzz = match fuzz_manifest.is_file() {
+        true => Some(read(&fuzz_manifest)? + &fuzz_bin_template(day)),
+        false => None,
+    };
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|e| Error::io(&path.display().to_string(), e))
+    };
+    write(&module, &module_template(day, title))?;
+    write(&registry, &source)?;
+    write(&tests, &test_source)?;
+    let mut changed = vec![module, registry, tests];
+    if let Some(manifest) = fuzz {
+        let target = root.join(format!("fuzz/fuzz_targets/day{:02}.rs", day));
+        write(&target, &fuzz_target_template(day))?;
+        write(&fuzz_manifest, &manifest)?;
+        changed.extend([target, fuzz_manifest]);
+    }
+    if !example.exists() {
+        write(&example, "")?;
+        changed.push(example);
+    }
+    Ok(changed)
+}
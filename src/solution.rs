@@ -0,0 +1,162 @@
+//! The [`Solution`] trait implemented by every day
+
+use crate::error::{ParseError, Result};
+use crate::json;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Puzzle part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{}`", s)),
+        }
+    }
+}
+
+/// Answer returned by a solver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, u32, i64, u64, usize);
+
+/// Numbers beyond `i128` are kept as text
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Solution for a single day
+///
+/// The input is parsed once and both parts are solved from the parsed value
+/// so that parsing and solving can be timed separately.
+pub trait Solution {
+    /// Parsed puzzle input shared by both parts
+    type Input;
+
+    /// Day number in the calendar
+    const DAY: u8;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Answer and timings of a single solved part
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Report {
+    /// The report as a JSON object with the times in milliseconds
+    pub fn to_json(&self) -> json::Object {
+        let answer = match &self.answer {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => json::string(s),
+        };
+        json::Object::new()
+            .value("day", self.day)
+            .value("part", self.part)
+            .raw("answer", answer)
+            .value("parse_ms", millis(self.parse_time))
+            .value("solve_ms", millis(self.solve_time))
+    }
+}
+
+/// Duration in milliseconds rounded to microseconds
+fn millis(d: Duration) -> f64 {
+    (d.as_secs_f64() * 1e6).round() / 1e3
+}
+
+/// Parse the input and solve the given parts of solution `S`
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Report>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut reports = vec![];
+    for part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        };
+        reports.push(Report {
+            day: S::DAY,
+            part: *part,
+            answer,
+            parse_time,
+            solve_time: start.elapsed(),
+        });
+    }
+    Ok(reports)
+}
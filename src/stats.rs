@@ -0,0 +1,123 @@
+//! Summary statistics for the report mode of `aoc report`
+//!
+//! Days that register a [`Reporter`] in [`REPORTS`] can print statistics of
+//! their parsed input beyond the puzzle answers.
+
+use crate::days::{day01, day02};
+use crate::error::ParseError;
+use std::fmt;
+
+/// Output of a report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Tables and a histogram for reading
+    #[default]
+    Text,
+    /// Comma separated values of the main table only
+    Csv,
+}
+
+/// Options of a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportOptions {
+    pub format: ReportFormat,
+    /// Number of histogram buckets
+    pub buckets: usize,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        ReportOptions {
+            format: ReportFormat::Text,
+            buckets: 10,
+        }
+    }
+}
+
+/// Renders the report of a day from its puzzle input
+pub type Reporter = fn(&str, &ReportOptions) -> Result<String, ParseError>;
+
+/// Registry of the days with a report
+pub const REPORTS: &[(u8, Reporter)] = &[
+    (1, day01::report),
+    (2, |input, options| day02::report(input, options, None)),
+];
+
+/// Percentile `p` (0 to 100) of sorted values by the nearest rank method
+pub fn percentile<T: Copy>(sorted: &[T], p: u8) -> Option<T> {
+    let rank = (p.min(100) as usize * sorted.len()).div_ceil(100);
+    sorted.get(rank.max(1) - 1).copied()
+}
+
+/// One bar of a [`Histogram`], counting the values from `min` to `max`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub min: i128,
+    pub max: i128,
+    pub count: usize,
+}
+
+/// Counts of values in equally wide buckets from the smallest to the
+/// largest value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Histogram {
+    pub buckets: Vec<Bucket>,
+}
+
+impl Histogram {
+    /// Width of the longest bar when displayed
+    const BAR_WIDTH: usize = 40;
+
+    /// Histogram of `values` in at most `buckets` buckets
+    ///
+    /// Buckets are at least one wide, so a narrow range of values gets fewer
+    /// of them. Values of any integer type up to 64 bits are counted in
+    /// `i128`, where their range always fits.
+    pub fn new<T: Copy + Into<i128>>(values: &[T], buckets: usize) -> Self {
+        let values: Vec<i128> = values.iter().map(|&value| value.into()).collect();
+        let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+            return Histogram::default();
+        };
+        let range = (max - min + 1) as u128;
+        let width = range.div_ceil(buckets.max(1) as u128);
+        let count = range.div_ceil(width) as usize;
+
+        let mut histogram: Vec<Bucket> = (0..count)
+            .map(|i| {
+                let start = min + (i as u128 * width) as i128;
+                Bucket {
+                    min: start,
+                    max: (start + width as i128 - 1).min(max),
+                    count: 0,
+                }
+            })
+            .collect();
+        for value in values {
+            histogram[((value - min) as u128 / width) as usize].count += 1;
+        }
+        Histogram { buckets: histogram }
+    }
+}
+
+impl fmt::Display for Histogram {
+    /// One line per bucket with a bar scaled to the largest count
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let largest = self.buckets.iter().map(|b| b.count).max().unwrap_or(0);
+        let width = |n: i128| n.to_string().len();
+        let min_width = self.buckets.iter().map(|b| width(b.min)).max().unwrap_or(0);
+        let max_width = self.buckets.iter().map(|b| width(b.max)).max().unwrap_or(0);
+        for bucket in &self.buckets {
+            let bar = (bucket.count * Self::BAR_WIDTH).div_ceil(largest.max(1));
+            write!(
+                f,
+                "{:>min_width$} - {:>max_width$} |",
+                bucket.min, bucket.max
+            )?;
+            if bar > 0 {
+                write!(f, " {}", "#".repeat(bar))?;
+            }
+            writeln!(f, " {}", bucket.count)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,369 @@
+//! Submitting answers to the puzzle server
+//!
+//! Every attempt is recorded in a local log. The log is consulted before
+//! posting so that answers known to be wrong are not sent again and the
+//! cooldown of the server after a wrong answer is respected.
+
+use crate::error::Error;
+use crate::fetch::{HttpFetcher, YEAR};
+use crate::http;
+use crate::input::input_dir;
+use crate::solution::Part;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Time to wait after a wrong answer when the server did not say otherwise
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Verdict of the puzzle server on a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the previous attempt
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved
+    AlreadySolved,
+    /// The response was not recognized
+    Unknown,
+}
+
+impl Outcome {
+    /// Interpret the HTML page returned by the server
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Outcome::Correct
+        } else if body.contains("too high") {
+            Outcome::TooHigh
+        } else if body.contains("too low") {
+            Outcome::TooLow
+        } else if body.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: parse_wait(body),
+            }
+        } else if body.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    /// Whether the server rejected the answer itself
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn keyword(&self) -> String {
+        match self {
+            Outcome::Correct => "correct".to_string(),
+            Outcome::TooHigh => "too-high".to_string(),
+            Outcome::TooLow => "too-low".to_string(),
+            Outcome::Wrong => "wrong".to_string(),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                format!("rate-limited:{}", wait.as_secs())
+            }
+            Outcome::RateLimited { wait: None } => "rate-limited".to_string(),
+            Outcome::AlreadySolved => "already-solved".to_string(),
+            Outcome::Unknown => "unknown".to_string(),
+        }
+    }
+
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        let outcome = match keyword {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "rate-limited" => Outcome::RateLimited { wait: None },
+            "already-solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown,
+            _ => {
+                let secs = keyword.strip_prefix("rate-limited:")?.parse().ok()?;
+                Outcome::RateLimited {
+                    wait: Some(Duration::from_secs(secs)),
+                }
+            }
+        };
+        Some(outcome)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Outcome::RateLimited { wait: None } => write!(f, "rate limited"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Parse the wait time from `You have 1m 5s left to wait.`
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for part in body[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        secs += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Reason for not submitting an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was solved with the given answer
+    AlreadySolved { answer: String },
+    /// The same answer was rejected before
+    KnownWrong { outcome: Outcome },
+    /// A numeric answer is outside the bounds given by earlier attempts
+    OutOfBounds { bound: String, outcome: Outcome },
+    /// The server asked to wait before the next attempt
+    Cooldown { remaining: Duration },
+    /// The answer cannot be typed into the answer field
+    Invalid { reason: String },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "already solved with answer `{}`", answer)
+            }
+            Refusal::KnownWrong { outcome } => {
+                write!(f, "this answer was already submitted: {}", outcome)
+            }
+            Refusal::OutOfBounds { bound, outcome } => {
+                write!(f, "`{}` was already {}", bound, outcome)
+            }
+            Refusal::Cooldown { remaining } => write!(
+                f,
+                "submitted too recently, wait {}s before the next attempt",
+                remaining.as_secs().max(1)
+            ),
+            Refusal::Invalid { reason } => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Single submission as recorded in the log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Log of all submissions, one tab separated attempt per line
+#[derive(Debug, Clone)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Default location next to the cached inputs of the year
+    pub fn default_path() -> PathBuf {
+        input_dir().join(YEAR.to_string()).join("submissions.log")
+    }
+
+    /// Read the log, an empty log if the file does not exist yet
+    ///
+    /// Lines that cannot be parsed are skipped.
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::io(&path.display().to_string(), e)),
+        };
+        let attempts = text.lines().filter_map(parse_attempt).collect();
+        Ok(SubmissionLog { path, attempts })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Check whether `answer` may be submitted at time `now`
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(Refusal::Invalid {
+                reason: "the answer must be a single word, pass the letters read from \
+                         the output with --answer"
+                    .to_string(),
+            });
+        }
+
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |a| a.day == day && a.part == part)
+        };
+        if let Some(solved) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved {
+                answer: solved.answer.clone(),
+            });
+        }
+        if let Some(wrong) = attempts().find(|a| a.answer == answer && a.outcome.is_wrong()) {
+            return Err(Refusal::KnownWrong {
+                outcome: wrong.outcome.clone(),
+            });
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            for attempt in attempts() {
+                let Ok(bound) = attempt.answer.parse::<i128>() else {
+                    continue;
+                };
+                let out_of_bounds = match attempt.outcome {
+                    Outcome::TooHigh => value >= bound,
+                    Outcome::TooLow => value <= bound,
+                    _ => false,
+                };
+                if out_of_bounds {
+                    return Err(Refusal::OutOfBounds {
+                        bound: attempt.answer.clone(),
+                        outcome: attempt.outcome.clone(),
+                    });
+                }
+            }
+        }
+
+        if let Some(ready) = self.next_allowed() {
+            if now < ready {
+                return Err(Refusal::Cooldown {
+                    remaining: Duration::from_secs(ready - now),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Earliest time of the next attempt after the last one
+    fn next_allowed(&self) -> Option<u64> {
+        let last = self.attempts.last()?;
+        let wait = match &last.outcome {
+            Outcome::RateLimited { wait } => wait.unwrap_or(DEFAULT_COOLDOWN),
+            outcome if outcome.is_wrong() => DEFAULT_COOLDOWN,
+            _ => return None,
+        };
+        Some(last.time + wait.as_secs())
+    }
+
+    /// Append an attempt to the log file
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Error> {
+        let io = |e| Error::io(&self.path.display().to_string(), e);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}",
+            attempt.time,
+            attempt.day,
+            attempt.part,
+            attempt.outcome.keyword(),
+            attempt.answer
+        )
+        .map_err(io)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(5, '\t');
+    Some(Attempt {
+        time: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        outcome: Outcome::from_keyword(fields.next()?)?,
+        answer: fields.next()?.to_string(),
+    })
+}
+
+/// Destination of submitted answers
+pub trait Submitter {
+    /// Post the answer and return the body of the response
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String, Error>;
+}
+
+/// Posts answers to `<base_url>/<year>/day/<day>/answer`
+impl Submitter for HttpFetcher {
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let headers = self.headers();
+        let headers: Vec<_> = headers.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let level = part.to_string();
+        let response = http::post_form(&url, &headers, &[("level", &level), ("answer", answer)])?;
+        if !response.is_success() {
+            let reason = format!("server responded with status {}", response.status);
+            return Err(Error::http(&url, &reason));
+        }
+        Ok(response.body)
+    }
+}
+
+/// Seconds since the Unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Submit an answer unless the log says it must not be, and record the attempt
+pub fn submit(
+    submitter: &dyn Submitter,
+    log: &mut SubmissionLog,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Outcome, Error> {
+    log.check(day, part, answer, now).map_err(Error::Refused)?;
+    let body = submitter.submit(YEAR, day, part, answer)?;
+    let outcome = Outcome::from_response(&body);
+    log.record(Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: outcome.clone(),
+    })?;
+    Ok(outcome)
+}
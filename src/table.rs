@@ -0,0 +1,59 @@
+//! Plain text and CSV tables for terminal output
+
+/// Render rows as left aligned columns under a header
+///
+/// Column widths are measured in characters. The header is separated from
+/// the rows by a line of dashes.
+pub fn render(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            let width = cell.chars().count();
+            match widths.get_mut(i) {
+                Some(w) => *w = (*w).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+
+    let line = |cells: &mut dyn Iterator<Item = &str>| {
+        let mut out = String::new();
+        for (cell, width) in cells.zip(&widths) {
+            out += &format!("{:<width$}  ", cell, width = width);
+        }
+        out.trim_end().to_string() + "\n"
+    };
+
+    let mut out = line(&mut header.iter().copied());
+    let total = widths.iter().sum::<usize>() + 2 * widths.len().saturating_sub(1);
+    out += &"-".repeat(total);
+    out.push('\n');
+    for row in rows {
+        out += &line(&mut row.iter().map(String::as_str));
+    }
+    out
+}
+
+/// Render rows as CSV with a header line
+///
+/// Cells containing commas, quotes or line breaks are quoted.
+pub fn csv(header: &[&str], rows: &[Vec<String>]) -> String {
+    let line = |cells: &mut dyn Iterator<Item = &str>| {
+        let cells: Vec<String> = cells
+            .map(|cell| {
+                if cell.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", cell.replace('"', "\"\""))
+                } else {
+                    cell.to_string()
+                }
+            })
+            .collect();
+        cells.join(",") + "\n"
+    };
+
+    let mut out = line(&mut header.iter().copied());
+    for row in rows {
+        out += &line(&mut row.iter().map(String::as_str));
+    }
+    out
+}
@@ -0,0 +1,81 @@
+//! The subset of TOML read and written by the files of the runner
+//!
+//! Supported are comments, table headers (checked by the callers), integers
+//! and single line basic strings, which is all `answers.toml` and `aoc.toml`
+//! need.
+
+use crate::error::ParseError;
+use crate::parse::Line;
+
+/// Split `key = value`, dropping a trailing comment after the value
+pub fn key_value<'a>(line: &Line<'a>) -> Result<(&'a str, &'a str), ParseError> {
+    let text = line.text;
+    let Some((key, value)) = text.split_once('=') else {
+        return Err(line.error_at_end("`=`"));
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(line.error_at(0, "key", ""));
+    }
+    Ok((key, value.trim()))
+}
+
+/// Integer value, dropping a trailing comment
+pub fn integer<T: std::str::FromStr>(
+    line: &Line,
+    value: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let value = value.split('#').next().unwrap_or_default().trim();
+    line.number(value, expected)
+}
+
+/// Decode a basic string with its escapes
+pub fn string(line: &Line, value: &str) -> Result<String, ParseError> {
+    let Some(body) = value.strip_prefix('"') else {
+        return Err(line.error(value, "string"));
+    };
+    let mut out = String::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                let rest = body[i + 1..].trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(line.error(rest, "end of line"));
+                }
+                return Ok(out);
+            }
+            '\\' => match chars.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, 'r')) => out.push('\r'),
+                Some((_, '"')) => out.push('"'),
+                Some((_, '\\')) => out.push('\\'),
+                Some((j, _)) => {
+                    return Err(line.error(&body[j..j + 1], "escape `n`, `t`, `r`, `\"` or `\\`"))
+                }
+                None => break,
+            },
+            c => out.push(c),
+        }
+    }
+    Err(line.error_at_end("closing `\"`"))
+}
+
+/// Encode a string as a basic string
+pub fn quote(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
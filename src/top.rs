@@ -0,0 +1,72 @@
+//! Selection of the largest values of a stream in bounded memory
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Keeps the `n` largest values pushed into it
+///
+/// The values are held in a min-heap of at most `n` entries, so a new value
+/// only has to be compared with the smallest of the ones kept.
+#[derive(Debug, Clone)]
+pub struct TopN<T> {
+    n: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopN<T> {
+    pub fn new(n: usize) -> Self {
+        TopN {
+            n,
+            heap: BinaryHeap::with_capacity(n),
+        }
+    }
+
+    /// Offer a value, which is kept if it is among the `n` largest so far
+    pub fn push(&mut self, value: T) {
+        if self.heap.len() < self.n {
+            self.heap.push(Reverse(value));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if value > smallest.0 {
+                *smallest = Reverse(value);
+            }
+        }
+    }
+
+    /// Number of values kept, at most `n`
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Smallest of the values kept
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek().map(|value| &value.0)
+    }
+
+    /// The values kept, largest first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|value| value.0)
+            .collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopN<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+/// The `n` largest values, largest first
+pub fn top_n<T: Ord>(values: impl IntoIterator<Item = T>, n: usize) -> Vec<T> {
+    let mut top = TopN::new(n);
+    top.extend(values);
+    top.into_sorted_vec()
+}
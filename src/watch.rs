@@ -0,0 +1,154 @@
+//! Change detection and answer comparison for `aoc watch`
+//!
+//! Files are polled for changes of their modification time and size, which
+//! needs nothing beyond the standard library and is plenty for a handful of
+//! files.
+
+use crate::solution::Part;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Modification time and size of a file, `None` if it does not exist
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Polls a fixed set of files for changes
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Watch `paths`, taking their current state as unchanged
+    ///
+    /// Paths that do not exist yet are watched for being created.
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect();
+        Watcher { files }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Files that changed since the previous call
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        for (path, old) in &mut self.files {
+            let new = stamp(path);
+            if new != *old {
+                *old = new;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    /// Block until some of the files change
+    ///
+    /// Editors often write a file in several steps, so the files are polled
+    /// until they have been quiet for one `interval`.
+    pub fn wait(&mut self, interval: Duration) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        loop {
+            thread::sleep(interval);
+            let more = self.changed();
+            if more.is_empty() && !changed.is_empty() {
+                return changed;
+            }
+            for path in more {
+                if !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+        }
+    }
+}
+
+/// Files to watch for a day: its solution module, its input and examples
+pub fn watched_paths(root: &Path, day: u8, input: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![
+        root.join(format!("src/days/day{:02}.rs", day)),
+        input.to_path_buf(),
+    ];
+    let examples = root.join("input/examples");
+    let name = format!("day{}", day);
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(&examples)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem == name || stem.starts_with(&format!("{}-", name)))
+        })
+        .collect();
+    fixtures.sort();
+    paths.extend(fixtures);
+    paths
+}
+
+/// Answers printed by `aoc run` in text format
+///
+/// Single line answers follow the `Day N part P:` header on the same line,
+/// multi-line answers on the lines after it.
+pub fn parse_answers(output: &str) -> Vec<(Part, String)> {
+    let mut answers: Vec<(Part, String)> = vec![];
+    for line in output.lines() {
+        let header = line
+            .strip_prefix("Day ")
+            .and_then(|rest| rest.split_once(" part "))
+            .and_then(|(_, rest)| rest.split_once(':'))
+            .and_then(|(part, answer)| Some((part.parse::<Part>().ok()?, answer)));
+        match header {
+            Some((part, answer)) => answers.push((part, answer.trim().to_string())),
+            None => {
+                if let Some((_, answer)) = answers.last_mut() {
+                    answer.push_str(line);
+                    answer.push('\n');
+                }
+            }
+        }
+    }
+    answers
+}
+
+/// How an answer compares to the one of the previous run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// There was no previous answer for the part
+    New,
+    Unchanged,
+    Changed {
+        previous: String,
+    },
+}
+
+/// Compare the answers of a run against the previous run
+pub fn compare(previous: &[(Part, String)], current: &[(Part, String)]) -> Vec<Change> {
+    current
+        .iter()
+        .map(
+            |(part, answer)| match previous.iter().find(|(p, _)| p == part) {
+                None => Change::New,
+                Some((_, old)) if old == answer => Change::Unchanged,
+                Some((_, old)) => Change::Changed {
+                    previous: old.clone(),
+                },
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,81 @@
+//! The answers registry and the checked-in `answers.toml`
+
+use advent_of_code_2022::answers::{input_hash, Answers, Verdict};
+use advent_of_code_2022::days::DAYS;
+use advent_of_code_2022::input::read_input_file;
+use advent_of_code_2022::solution::{Answer, Part};
+
+#[test]
+fn round_trip() {
+    let mut answers = Answers::default();
+    answers.insert(10, "00ff", Part::One, Answer::from(13140));
+    answers.insert(10, "00ff", Part::Two, Answer::from("#\"\\\n"));
+    answers.insert(1, "1234", Part::Two, Answer::from(45000));
+
+    let text = answers.to_string();
+    assert!(text.contains("part2 = \"#\\\"\\\\\\n\""));
+    let parsed = Answers::parse(&text).unwrap();
+    assert_eq!(
+        parsed.get(10, "00ff", Part::Two),
+        Some(&Answer::from("#\"\\\n"))
+    );
+    assert_eq!(parsed.get(1, "1234", Part::One), None);
+    assert_eq!(parsed.entries().len(), 2);
+}
+
+#[test]
+fn verdicts() {
+    let answers =
+        Answers::parse("[[answer]]\nday = 6 # comment\ninput = \"ab\"\npart1 = 7\n").unwrap();
+    let check = |part, n: i32| answers.check(6, "ab", part, &Answer::from(n));
+    assert_eq!(check(Part::One, 7), Verdict::Pass);
+    assert_eq!(
+        check(Part::One, 8),
+        Verdict::Fail {
+            expected: Answer::from(7)
+        }
+    );
+    assert_eq!(check(Part::Two, 19), Verdict::Missing);
+}
+
+#[test]
+fn wide_numbers_are_kept_exactly() {
+    assert_eq!(
+        Answer::from(u64::MAX as u128 * 3),
+        Answer::Number(u64::MAX as i128 * 3)
+    );
+    assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+}
+
+#[test]
+fn errors_are_located() {
+    let e = Answers::parse("[[answer]]\nday = 1\ninput = \"x\"\ncolour = 3\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (4, 1, "colour"));
+
+    let e = Answers::parse("[[answer]]\ninput = \"x\"\n").unwrap_err();
+    assert_eq!(e.expected, "`day` key in the table");
+}
+
+#[test]
+fn checked_in_answers_pass() {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let text = read_input_file(format!("{}/answers.toml", root)).unwrap();
+    let answers = Answers::parse(&text).unwrap();
+
+    for day in DAYS {
+        // Days scaffolded with `aoc new` have no input yet
+        let Ok(input) = read_input_file(format!("{}/input/day{}.txt", root, day.day)) else {
+            continue;
+        };
+        let hash = input_hash(&input);
+        for report in (day.run)(&input, &Part::ALL).unwrap() {
+            assert_eq!(
+                answers.check(day.day, &hash, report.part, &report.answer),
+                Verdict::Pass,
+                "day {} part {}",
+                day.day,
+                report.part
+            );
+        }
+    }
+}
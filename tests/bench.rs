@@ -0,0 +1,135 @@
+//! Statistics and baseline files of the benchmark harness
+
+use advent_of_code_2022::bench::{Baseline, Stats, Timing};
+use advent_of_code_2022::memory::{format_bytes, Usage};
+use advent_of_code_2022::solution::Part;
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn stats_of_samples() {
+    assert_eq!(Stats::from_samples(&mut []), None);
+
+    let odd = Stats::from_samples(&mut [ms(5), ms(1), ms(3)]).unwrap();
+    assert_eq!((odd.min, odd.median, odd.max), (ms(1), ms(3), ms(5)));
+
+    let even = Stats::from_samples(&mut [ms(4), ms(1), ms(2), ms(8)]).unwrap();
+    assert_eq!((even.min, even.median, even.max), (ms(1), ms(3), ms(8)));
+}
+
+#[test]
+fn baseline_round_trip() {
+    let stats = |n| Stats::from_samples(&mut [ms(n)]).unwrap();
+    let timing = Timing {
+        day: 12,
+        part: Part::Two,
+        parse: stats(2),
+        solve: stats(40),
+        memory: None,
+    };
+    let baseline = Baseline::from_timings(std::slice::from_ref(&timing));
+    let saved = baseline.to_string();
+    assert_eq!(saved, "# day part parse_ns solve_ns\n12 2 2000000 40000000\n");
+    assert_eq!(Baseline::parse(&saved).unwrap(), baseline);
+
+    let slower = Baseline::parse("12 2 1000000 20000000\n").unwrap();
+    assert_eq!(timing.compare(&slower), Some(100.0));
+    assert_eq!(timing.compare(&Baseline::default()), None);
+}
+
+#[test]
+fn baseline_errors_are_located() {
+    let e = Baseline::parse("# comment\n3 1 10 x\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 8));
+}
+
+#[test]
+fn baseline_with_memory_usage() {
+    let stats = |n| Stats::from_samples(&mut [ms(n)]).unwrap();
+    let timing = Timing {
+        day: 1,
+        part: Part::One,
+        parse: stats(1),
+        solve: stats(1),
+        memory: Some(Usage {
+            allocations: 30,
+            bytes: 4096,
+            peak: 1500,
+        }),
+    };
+    let baseline = Baseline::from_timings(std::slice::from_ref(&timing));
+    let saved = baseline.to_string();
+    assert_eq!(
+        saved,
+        "# day part parse_ns solve_ns [allocations bytes peak_bytes]\n\
+         1 1 1000000 1000000 30 4096 1500\n"
+    );
+    assert_eq!(Baseline::parse(&saved).unwrap(), baseline);
+
+    let leaner = Baseline::parse("1 1 1000000 1000000 20 2048 1000\n").unwrap();
+    assert_eq!(timing.compare_memory(&leaner), Some((50.0, 50.0)));
+    // Time only baselines still compare the time
+    let timed = Baseline::parse("1 1 1000000 1000000\n").unwrap();
+    assert_eq!(timing.compare(&timed), Some(0.0));
+    assert_eq!(timing.compare_memory(&timed), None);
+}
+
+#[test]
+fn timings_are_described_against_baselines() {
+    let stats = |n| Stats::from_samples(&mut [ms(n)]).unwrap();
+    let timing = Timing {
+        day: 1,
+        part: Part::One,
+        parse: stats(1),
+        solve: stats(3),
+        memory: Some(Usage {
+            allocations: 30,
+            bytes: 4096,
+            peak: 1500,
+        }),
+    };
+    let plain = timing.to_string();
+    assert_eq!(timing.describe_against(&Baseline::default()), plain);
+
+    let timed = Baseline::parse("1 1 1000000 1000000\n").unwrap();
+    assert_eq!(
+        timing.describe_against(&timed),
+        format!("{} (+100.0% vs baseline)", plain)
+    );
+    let measured = Baseline::parse("1 1 1000000 1000000 20 2048 1000\n").unwrap();
+    assert_eq!(
+        timing.describe_against(&measured),
+        format!(
+            "{} (+100.0%, allocs +50.0%, peak +50.0% vs baseline)",
+            plain
+        )
+    );
+}
+
+#[test]
+fn baseline_files_report_errors() {
+    let dir = std::env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let missing = dir.join("missing.txt");
+    let e = Baseline::load(&missing).unwrap_err();
+    assert!(e.starts_with(&missing.display().to_string()), "{}", e);
+
+    let broken = dir.join("broken.txt");
+    std::fs::write(&broken, "1 1 10 x\n").unwrap();
+    let e = Baseline::load(&broken).unwrap_err();
+    assert!(e.contains("broken.txt:1:8"), "{}", e);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn byte_counts_are_formatted() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+}
@@ -0,0 +1,178 @@
+//! The `aoc` binary run against temporary input directories
+
+mod common;
+
+use common::MockServer;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Run `aoc` in `dir` with `stdin`, isolated from the config files of the
+/// user and the checked-in inputs
+fn aoc(dir: &Path, args: &[&str], env: &[(&str, &str)], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(dir)
+        .env("AOC_INPUT_DIR", dir.join("input"))
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_BASE_URL")
+        .env_remove("AOC_LOG")
+        .envs(env.iter().copied())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn parallel_runs_fetch_each_input_once() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/2022/day/1/input" => (200, "1000\n2000\n\n500\n".to_string()),
+        _ => (404, "Not Found".to_string()),
+    });
+    let dir = temp_dir("jobs");
+    let env = [
+        ("AOC_BASE_URL", server.url.as_str()),
+        ("AOC_SESSION", "secret"),
+    ];
+
+    let out = stdout(&aoc(&dir, &["run", "1", "--jobs", "2"], &env, ""));
+    assert!(out.contains("1    1     3000"), "{}", out);
+    assert!(out.contains("1    2     3500"), "{}", out);
+    assert_eq!(server.requests().len(), 1);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn parallel_runs_read_stdin_once() {
+    let dir = temp_dir("stdin");
+    let args = ["run", "1", "--jobs", "2", "--input", "-"];
+    let out = stdout(&aoc(&dir, &args, &[], "1\n\n2\n\n3\n\n4\n"));
+    assert!(out.contains("1    1     4 "), "{}", out);
+    assert!(out.contains("1    2     9 "), "{}", out);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn report_scores_the_given_hand_table() {
+    let dir = temp_dir("report");
+    let guide = "A Y\nB X\nC Z\n";
+    let args = [
+        "report",
+        "2",
+        "--input",
+        "-",
+        "--table",
+        "X=rock,Y=paper,Z=scissors",
+    ];
+    let out = stdout(&aoc(&dir, &args, &[], guide));
+    assert!(
+        out.contains("X=rock, Y=paper, Z=scissors scores 15, rank 2 of 6"),
+        "{}",
+        out
+    );
+
+    let args = [
+        "report",
+        "2",
+        "--input",
+        "-",
+        "--csv",
+        "-t",
+        "Z=rock,X=scissors",
+    ];
+    let out = stdout(&aoc(&dir, &args, &[], guide));
+    assert_eq!(out, "X,Y,Z,score\nscissors,paper,rock,24\n");
+
+    let args = ["report", "2", "--table", "X=lizard"];
+    let output = aoc(&dir, &args, &[], "");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("error: unknown hand `lizard`"),
+        "{}",
+        stderr
+    );
+
+    let args = ["report", "1", "--table", "X=rock"];
+    let output = aoc(&dir, &args, &[], "");
+    assert_eq!(output.status.code(), Some(2));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn new_scaffolds_the_checkout_of_the_working_directory() {
+    let dir = temp_dir("new");
+    for sub in ["src/days", "tests", "input/examples"] {
+        fs::create_dir_all(dir.join(sub)).unwrap();
+    }
+    fs::write(
+        dir.join("src/days/mod.rs"),
+        "pub mod day06;\n\npub const DAYS: &[Day] = &[\n    Day::of::<day06::Day06>(),\n];\n",
+    )
+    .unwrap();
+    fs::write(dir.join("tests/days.rs"), "// tests\n").unwrap();
+
+    let out = stdout(&aoc(&dir.join("tests"), &["new", "7"], &[], ""));
+    assert!(out.contains("src/days/day07.rs"), "{}", out);
+    assert!(dir.join("src/days/day07.rs").is_file());
+
+    let outside = temp_dir("new-outside");
+    let output = aoc(&outside, &["new", "7"], &[], "");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no src/days/mod.rs"), "{}", stderr);
+
+    fs::remove_dir_all(dir).unwrap();
+    fs::remove_dir_all(outside).unwrap();
+}
+
+#[test]
+fn option_values_are_not_logging_flags() {
+    let dir = temp_dir("values");
+    fs::write(dir.join("-v"), "1\n\n2\n").unwrap();
+
+    let out = stdout(&aoc(
+        &dir,
+        &["run", "1", "-p", "1", "--input", "-v", "-q"],
+        &[],
+        "",
+    ));
+    assert_eq!(out, "Day 1 part 1: 2\n");
+
+    let output = aoc(&dir, &["report", "2", "--table", "-v"], &[], "");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("-v"), "{}", stderr);
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,79 @@
+//! Reading and merging of `aoc.toml`
+
+use advent_of_code_2022::config::Config;
+use std::fs;
+use std::path::PathBuf;
+
+#[test]
+fn every_key_is_read() {
+    let config = Config::parse(
+        "# team defaults\n\
+         input_dir = \"inputs\"\n\
+         format = \"json\"  # for scripts\n\
+         log = \"info,day05=trace\"\n\
+         \n\
+         [bench]\n\
+         runs = 25\n\
+         \n\
+         [server]\n\
+         base_url = \"http://localhost:8080\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            input_dir: Some(PathBuf::from("inputs")),
+            format: Some("json".to_string()),
+            log: Some("info,day05=trace".to_string()),
+            bench_runs: Some(25),
+            base_url: Some("http://localhost:8080".to_string()),
+        }
+    );
+    assert_eq!(Config::parse("").unwrap(), Config::default());
+}
+
+#[test]
+fn invalid_values_are_located() {
+    let error = |input| {
+        let e = Config::parse(input).unwrap_err();
+        (e.line, e.column, e.expected)
+    };
+    assert_eq!(
+        error("format = \"yaml\"\n"),
+        (1, 10, "`\"text\"` or `\"json\"`".to_string())
+    );
+    assert_eq!(error("\n[bench]\nruns = 0\n").0, 3);
+    assert_eq!(error("[bench]\nbase_url = \"x\"\n").2, "`runs`");
+    assert_eq!(error("[fetch]\n").2, "`[bench]` or `[server]`");
+    assert_eq!(error("log = \"day05=loud\"\n").1, 7);
+}
+
+#[test]
+fn project_file_is_found_upwards_and_overrides_user_file() {
+    let root = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let (project, user) = (root.join("project"), root.join("user"));
+    let nested = project.join("src/days");
+    fs::create_dir_all(&nested).unwrap();
+    fs::create_dir_all(&user).unwrap();
+    fs::write(project.join("aoc.toml"), "input_dir = \"inputs\"\n").unwrap();
+    fs::write(
+        user.join("aoc.toml"),
+        "input_dir = \"/elsewhere\"\nformat = \"json\"\n",
+    )
+    .unwrap();
+
+    let config = Config::load_from(&nested, Some(&user)).unwrap();
+    // Relative directories are resolved against the file
+    assert_eq!(config.input_dir, Some(project.join("inputs")));
+    assert_eq!(config.format.as_deref(), Some("json"));
+
+    let outside = Config::load_from(&root, Some(&user)).unwrap();
+    assert_eq!(outside.input_dir, Some(PathBuf::from("/elsewhere")));
+
+    fs::write(user.join("aoc.toml"), "format = json\n").unwrap();
+    let e = Config::load_from(&root, Some(&user)).unwrap_err();
+    assert!(e.to_string().contains("aoc.toml:1:10"), "{}", e);
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,255 @@
+//! Regression tests for every day: the official examples and our own inputs
+
+use advent_of_code_2022::days::*;
+use advent_of_code_2022::solution::{Answer, Solution};
+
+/// Parse `input` and check both parts against the expected answers
+fn check<S: Solution>(input: &str, part1: impl Into<Answer>, part2: impl Into<Answer>) {
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("day {}: {}", S::DAY, e));
+    assert_eq!(S::part1(&parsed), part1.into(), "day {} part 1", S::DAY);
+    assert_eq!(S::part2(&parsed), part2.into(), "day {} part 2", S::DAY);
+}
+
+/// CRT image drawn with `.` for dark pixels, as rendered by Day 10 part 2
+fn crt(rows: &[&str]) -> String {
+    rows.iter()
+        .map(|row| row.replace('.', " ") + "\n")
+        .collect()
+}
+
+macro_rules! example {
+    ($(#[$attr:meta])* $name:ident, $day:ty, $file:literal, $part1:expr, $part2:expr) => {
+        #[test]
+        $(#[$attr])*
+        fn $name() {
+            check::<$day>(
+                include_str!(concat!("../input/examples/", $file)),
+                $part1,
+                $part2,
+            );
+        }
+    };
+}
+
+macro_rules! answer {
+    ($name:ident, $day:ty, $file:literal, $part1:expr, $part2:expr) => {
+        #[test]
+        fn $name() {
+            check::<$day>(include_str!(concat!("../input/", $file)), $part1, $part2);
+        }
+    };
+}
+
+example!(day01_example, day01::Day01, "day1.txt", 24000, 45000);
+example!(day02_example, day02::Day02, "day2.txt", 15, 12);
+example!(day03_example, day03::Day03, "day3.txt", 157, 70);
+example!(day04_example, day04::Day04, "day4.txt", 2, 4);
+example!(day05_example, day05::Day05, "day5.txt", "CMZ", "MCD");
+example!(day06_example, day06::Day06, "day6.txt", 7, 19);
+example!(day07_example, day07::Day07, "day7.txt", 95437, 24933642);
+example!(day08_example, day08::Day08, "day8.txt", 21, 8);
+example!(day09_example, day09::Day09, "day9.txt", 13, 1);
+example!(
+    day10_example,
+    day10::Day10,
+    "day10.txt",
+    13140,
+    crt(&[
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ])
+);
+example!(
+    day11_example,
+    day11::Day11,
+    "day11.txt",
+    10605,
+    2713310158u64
+);
+example!(day12_example, day12::Day12, "day12.txt", 31, 29);
+example!(day13_example, day13::Day13, "day13.txt", 13, 140);
+
+#[test]
+fn day09_larger_example() {
+    let input = include_str!("../input/examples/day9-larger.txt");
+    let parsed = day09::Day09::parse(input).unwrap();
+    assert_eq!(day09::Day09::part2(&parsed), Answer::from(36));
+}
+
+answer!(day01_answer, day01::Day01, "day1.txt", 72017, 212520);
+answer!(day02_answer, day02::Day02, "day2.txt", 10718, 14652);
+answer!(day03_answer, day03::Day03, "day3.txt", 7597, 2607);
+answer!(day04_answer, day04::Day04, "day4.txt", 433, 852);
+answer!(
+    day05_answer,
+    day05::Day05,
+    "day5.txt",
+    "TLNGFGMFN",
+    "FGLQJCMBD"
+);
+answer!(day06_answer, day06::Day06, "day6.txt", 1833, 3425);
+answer!(day07_answer, day07::Day07, "day7.txt", 1232307, 7268994);
+answer!(day08_answer, day08::Day08, "day8.txt", 1776, 234416);
+answer!(day09_answer, day09::Day09, "day9.txt", 6266, 2369);
+answer!(
+    day10_answer,
+    day10::Day10,
+    "day10.txt",
+    14160,
+    crt(&[
+        "###....##.####.###..###..####.####..##..",
+        "#..#....#.#....#..#.#..#.#....#....#..#.",
+        "#..#....#.###..#..#.#..#.###..###..#....",
+        "###.....#.#....###..###..#....#....#....",
+        "#.#..#..#.#....#.#..#....#....#....#..#.",
+        "#..#..##..####.#..#.#....####.#.....##..",
+    ])
+);
+answer!(
+    day11_answer,
+    day11::Day11,
+    "day11.txt",
+    101436,
+    19754471646u64
+);
+answer!(day12_answer, day12::Day12, "day12.txt", 425, 418);
+answer!(day13_answer, day13::Day13, "day13.txt", 5623, 20570);
+
+#[test]
+fn day07_tree_with_enough_free_space() {
+    check::<day07::Day07>("$ cd /\n$ ls\n100 a.txt\n", 100, 100);
+}
+
+/// Description of a day 11 monkey throwing all its items to `target`
+fn monkey(id: u64, items: &str, divisor: u64, target: u64) -> String {
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = old * old\n  \
+         Test: divisible by {}\n    If true: throw to monkey {}\n    \
+         If false: throw to monkey {}\n",
+        id, items, divisor, target, target
+    )
+}
+
+#[test]
+fn day11_invalid_troops_are_rejected() {
+    let error = |monkeys: &[String]| {
+        let e = day11::Day11::parse(&monkeys.join("\n")).err().unwrap();
+        (e.line, e.column, e.expected)
+    };
+    // A single monkey can only throw to itself
+    assert_eq!(
+        error(&[monkey(0, "1", 2, 0)]),
+        (5, 30, "id of another monkey".to_string())
+    );
+    assert_eq!(
+        error(&[monkey(0, "1", 2, 1)]),
+        (5, 30, "existing monkey id".to_string())
+    );
+    assert_eq!(
+        error(&[monkey(0, "1", 2, 1), monkey(0, "1", 3, 1)]),
+        (8, 8, "id of a new monkey".to_string())
+    );
+    // The product of the divisors is 10^21
+    let monkeys = [
+        monkey(0, "1", 10_000_000, 1),
+        monkey(1, "1", 10_000_000, 2),
+        monkey(2, "1", 10_000_000, 0),
+    ];
+    assert_eq!(
+        error(&monkeys),
+        (
+            18,
+            22,
+            "divisor keeping the product of all divisors within u64".to_string()
+        )
+    );
+}
+
+#[test]
+fn day11_monkeys_need_a_header() {
+    let first = monkey(0, "1", 2, 1);
+    let second = monkey(1, "1", 3, 0);
+    let headerless = second.split_once('\n').unwrap().1;
+    let e = day11::Day11::parse(&format!("{}\n{}", first, headerless))
+        .err()
+        .unwrap();
+    assert_eq!((e.line, e.column), (8, 3));
+    assert_eq!(e.expected, "`Monkey <id>:` header");
+
+    let e = day11::Day11::parse(&format!("{}{}", first, second))
+        .err()
+        .unwrap();
+    assert_eq!((e.line, e.column), (7, 1));
+    assert_eq!(e.expected, "blank line before the next monkey");
+
+    // Extra blank lines between and after the monkeys are skipped
+    let spaced = day11::Day11::parse(&format!("\n{}\n\n\n{}\n\n", first, second)).unwrap();
+    let plain = day11::Day11::parse(&format!("{}\n{}", first, second)).unwrap();
+    assert_eq!(day11::Day11::part1(&spaced), day11::Day11::part1(&plain));
+}
+
+#[test]
+fn day11_large_divisors_do_not_overflow_worry_levels() {
+    let monkeys = [
+        monkey(0, "999999999999", 1_000_000_007, 1),
+        monkey(1, "999999999999", 1_000_000_007, 0),
+    ];
+    let parsed = day11::Day11::parse(&monkeys.join("\n")).unwrap();
+    // Both items pass both monkeys every round after the first one
+    assert_eq!(day11::Day11::part1(&parsed), Answer::from(39 * 40));
+    assert_eq!(day11::Day11::part2(&parsed), Answer::from(19999u64 * 20000));
+}
+
+#[test]
+fn day10_register_overflow_is_rejected() {
+    let e = day10::Day10::parse("addx 2147483646\nnoop\naddx 1\n").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 6));
+    assert_eq!(e.expected, "number keeping the register within i32");
+
+    // The largest register still gives a signal strength
+    let mut input = "addx 2147483646\n".to_string();
+    input += &"noop\n".repeat(20);
+    let parsed = day10::Day10::parse(&input).unwrap();
+    assert_eq!(
+        day10::Day10::part1(&parsed),
+        Answer::Number(20 * i32::MAX as i128)
+    );
+}
+
+#[test]
+fn day12_unreachable_signal_has_no_path() {
+    let parsed = day12::Day12::parse("SbE\n").unwrap();
+    assert_eq!(day12::Day12::part1(&parsed), Answer::from(day12::NO_PATH));
+    assert_eq!(day12::Day12::part2(&parsed), Answer::from(day12::NO_PATH));
+
+    // Walled in start, but another square at the lowest elevation gets there
+    let input = format!("S{}\nz{}E\n", "z".repeat(26), "abcdefghijklmnopqrstuvwxy");
+    let parsed = day12::Day12::parse(&input).unwrap();
+    assert_eq!(day12::Day12::part1(&parsed), Answer::from(day12::NO_PATH));
+    assert_eq!(day12::Day12::part2(&parsed), Answer::from(25));
+}
+
+#[test]
+fn day13_equal_pair_is_not_in_order() {
+    let parsed = day13::Day13::parse("[1,2]\n[1,2]\n\n[1]\n[3]\n").unwrap();
+    assert_eq!(day13::Day13::part1(&parsed), Answer::from(2));
+    assert_eq!(day13::Day13::part2(&parsed), Answer::from(4 * 6));
+}
+
+#[test]
+fn day05_layouts_with_two_digit_stacks() {
+    let layout = "\
+[A]                                     [K]
+[B] [C] [D] [E] [F] [G] [H] [I] [J] [L] [M]
+ 1   2   3   4   5   6   7   8   9   10  11
+";
+    let input = format!("{}\nmove 2 from 11 to 10\n", layout);
+    let parsed = day05::Day05::parse(&input).unwrap();
+    assert_eq!(parsed.to_string(), input);
+    // The tenth stack counts too, the emptied eleventh one does not
+    assert_eq!(day05::Day05::part1(&parsed), Answer::from("ACDEFGHIJM"));
+}
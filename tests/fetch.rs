@@ -0,0 +1,100 @@
+//! Input cache and fetching against a local stand-in of the puzzle server
+
+mod common;
+
+use advent_of_code_2022::error::Error;
+use advent_of_code_2022::fetch::{HttpFetcher, InputCache, InputProvider};
+use advent_of_code_2022::http::curl_config;
+use common::MockServer;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Server handing out the input of day 1 to the session `secret`
+fn puzzle_server() -> MockServer {
+    MockServer::start(|request| {
+        match (
+            request.path.as_str(),
+            request.headers.get("cookie").map(String::as_str),
+        ) {
+            ("/2022/day/1/input", Some("session=secret")) => (200, "1000\n2000\n".to_string()),
+            (_, Some("session=secret")) => (404, "Not Found".to_string()),
+            _ => (400, "Please log in".to_string()),
+        }
+    })
+}
+
+#[test]
+fn missing_input_is_fetched_and_cached() {
+    let server = puzzle_server();
+    let dir = temp_dir("cached");
+    let provider = InputProvider::new(InputCache::new(&dir))
+        .with_fetcher(HttpFetcher::new(&server.url, "secret"));
+
+    assert_eq!(provider.input(2022, 1).unwrap(), "1000\n2000\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("2022/day1.txt")).unwrap(),
+        "1000\n2000\n"
+    );
+
+    // The second lookup is served from the cache
+    assert_eq!(provider.input(2022, 1).unwrap(), "1000\n2000\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn checked_in_input_takes_precedence() {
+    let server = puzzle_server();
+    let dir = temp_dir("flat");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("day1.txt"), "5\n").unwrap();
+    let provider = InputProvider::new(InputCache::new(&dir))
+        .with_fetcher(HttpFetcher::new(&server.url, "secret"));
+
+    assert_eq!(provider.input(2022, 1).unwrap(), "5\n");
+    assert!(server.requests().is_empty());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn server_errors_are_reported() {
+    let server = puzzle_server();
+    let dir = temp_dir("errors");
+
+    let provider = InputProvider::new(InputCache::new(&dir))
+        .with_fetcher(HttpFetcher::new(&server.url, "wrong"));
+    match provider.input(2022, 1) {
+        Err(Error::Http { url, reason }) => {
+            assert_eq!(url, format!("{}/2022/day/1/input", server.url));
+            assert_eq!(reason, "server responded with status 400");
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert!(!dir.join("2022/day1.txt").exists());
+
+    let offline = InputProvider::new(InputCache::new(&dir));
+    assert!(matches!(offline.input(2022, 2), Err(Error::Io { .. })));
+}
+
+#[test]
+fn curl_gets_the_session_on_stdin() {
+    let config = curl_config(
+        &[("Cookie", "session=a\"b\\c")],
+        Some("level=1&answer=@x\ny"),
+    );
+    assert_eq!(
+        config,
+        "header = \"Cookie: session=a\\\"b\\\\c\"\n\
+         data-raw = \"level=1&answer=@x\\ny\"\n"
+    );
+}
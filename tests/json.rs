@@ -0,0 +1,35 @@
+//! JSON encoding of answers and parse errors
+
+use advent_of_code_2022::error::ParseError;
+use advent_of_code_2022::json;
+use advent_of_code_2022::solution::{Answer, Part, Report};
+use std::time::Duration;
+
+#[test]
+fn strings_are_escaped() {
+    assert_eq!(json::string("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+}
+
+#[test]
+fn report_as_json() {
+    let report = Report {
+        day: 10,
+        part: Part::Two,
+        answer: Answer::from("#.\n"),
+        parse_time: Duration::from_micros(1500),
+        solve_time: Duration::from_nanos(2),
+    };
+    assert_eq!(
+        report.to_json().to_string(),
+        r##"{"day":10,"part":2,"answer":"#.\n","parse_ms":1.5,"solve_ms":0}"##
+    );
+}
+
+#[test]
+fn parse_error_as_json() {
+    let e = ParseError::new(3, 7, "number", "x").with_file("in.txt");
+    assert_eq!(
+        e.to_json().to_string(),
+        r#"{"file":"in.txt","line":3,"column":7,"expected":"number","found":"x","message":"expected number, found `x`"}"#
+    );
+}
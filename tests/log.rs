@@ -0,0 +1,41 @@
+//! Filtering of log messages by level and target
+
+use advent_of_code_2022::log::{Filter, Level};
+
+const DAY05: &str = "advent_of_code_2022::days::day05";
+const DAY13: &str = "advent_of_code_2022::days::day13";
+
+#[test]
+fn default_level_applies_to_every_target() {
+    let filter = Filter::new(Level::Debug);
+    assert!(filter.enabled(Level::Debug, DAY05));
+    assert!(filter.enabled(Level::Warn, DAY13));
+    assert!(!filter.enabled(Level::Trace, DAY05));
+    assert!(!Filter::new(Level::Off).enabled(Level::Error, DAY05));
+}
+
+#[test]
+fn directives_select_days() {
+    let filter = Filter::parse("warn, day05=trace, day13", Level::Off).unwrap();
+    assert_eq!(filter.default, Level::Warn);
+    assert!(filter.enabled(Level::Trace, DAY05));
+    assert!(filter.enabled(Level::Trace, DAY13));
+    assert!(!filter.enabled(Level::Info, "advent_of_code_2022::days::day1"));
+    assert_eq!(filter.max_level(), Level::Trace);
+}
+
+#[test]
+fn later_directives_win() {
+    let mut filter = Filter::parse("day05=trace", Level::Warn).unwrap();
+    filter.add("advent_of_code_2022::days=error").unwrap();
+    assert!(!filter.enabled(Level::Warn, DAY05));
+    assert!(filter.enabled(Level::Warn, "advent_of_code_2022::bench"));
+}
+
+#[test]
+fn invalid_level_is_rejected() {
+    assert_eq!(
+        Filter::parse("day05=loud", Level::Warn),
+        Err("invalid log level `loud`".to_string())
+    );
+}
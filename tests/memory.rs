@@ -0,0 +1,29 @@
+//! Allocation counting of the `--memory` mode
+
+use advent_of_code_2022::memory::{self, CountingAllocator};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn allocations_are_counted_while_measuring() {
+    assert!(memory::is_counting());
+
+    let (_, usage) = memory::measure(|| black_box(vec![0u8; 1000]));
+    assert!(usage.allocations >= 1);
+    assert!(usage.bytes >= 1000);
+    assert!(usage.peak >= 1000);
+}
+
+#[test]
+fn peak_is_the_most_in_use_at_once() {
+    let (_, usage) = memory::measure(|| {
+        for _ in 0..10 {
+            drop(black_box(vec![0u8; 4096]));
+        }
+    });
+    assert!(usage.allocations >= 10);
+    assert!(usage.bytes >= 10 * 4096);
+    assert!(usage.peak < 2 * 4096, "peak {}", usage.peak);
+}
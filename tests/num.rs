@@ -0,0 +1,81 @@
+//! Checked totals of day 1 in different numeric types
+
+use advent_of_code_2022::days::day01::{self, Day01};
+use advent_of_code_2022::num::{BigUint, Total};
+use advent_of_code_2022::solution::{Answer, Solution};
+
+fn big(digits: &str) -> BigUint {
+    digits.parse().unwrap()
+}
+
+#[test]
+fn big_integers_add_and_compare() {
+    let max = u128::MAX.to_string();
+    let sum = &big(&max) + &big("1");
+    assert_eq!(sum.to_string(), "340282366920938463463374607431768211456");
+    assert!(sum > big(&max));
+    assert!(big("999999999") < big("1000000000"));
+    assert_eq!(big("000120"), BigUint::from(120));
+    assert_eq!(big("0").to_string(), "0");
+    assert_eq!(format!("{:>5}", big("42")), "   42");
+    assert_eq!(big("1000000000").to_f64(), 1e9);
+    for invalid in ["", "-1", "1_000", " 1"] {
+        assert!(invalid.parse::<BigUint>().is_err(), "{:?}", invalid);
+    }
+}
+
+#[test]
+fn overflowing_totals_name_the_elf() {
+    let e = Day01::parse("1\n\n18446744073709551615\n1\n").unwrap_err();
+    assert_eq!((e.line, e.column), (4, 1));
+    assert_eq!(
+        e.message(),
+        "expected calorie count keeping the total of elf 2 within u64, found `1`"
+    );
+
+    let input = "1\n\n2147483647\n1\n";
+    let e = day01::totals::<i32>(input).unwrap_err();
+    assert_eq!((e.line, e.column), (4, 1));
+
+    assert_eq!(day01::totals::<u64>(input).unwrap(), vec![1, 2147483648]);
+    let e = day01::totals::<u64>("18446744073709551615\n1\n").unwrap_err();
+    assert!(
+        e.expected.ends_with("of elf 1 within u64"),
+        "{}",
+        e.expected
+    );
+}
+
+#[test]
+fn signs_depend_on_the_type() {
+    assert_eq!(day01::totals::<i128>("-5\n3\n").unwrap(), vec![-2]);
+    let e = day01::totals::<u64>("-5\n").unwrap_err();
+    assert_eq!(e.message(), "expected calorie count, found `-5`");
+}
+
+#[test]
+fn arbitrary_precision_totals_do_not_overflow() {
+    let huge = u128::MAX.to_string();
+    let input = format!("{0}\n{0}\n\n7\n", huge);
+    let top = day01::top_totals::<BigUint, _>(input.as_bytes(), "stress", 2).unwrap();
+    assert_eq!(top, vec![&big(&huge) + &big(&huge), BigUint::from(7)]);
+}
+
+#[test]
+fn top_three_sum_does_not_overflow_u64() {
+    let max = u64::MAX.to_string();
+    let input = format!("1\n\n{0}\n\n{0}\n\n{0}\n", max);
+    let calories = Day01::parse(&input).unwrap();
+    assert_eq!(calories, vec![u64::MAX; 3]);
+    assert_eq!(
+        Day01::part2(&calories),
+        Answer::Number(3 * u64::MAX as i128)
+    );
+}
+
+#[test]
+fn negative_calories_are_rejected() {
+    let e = Day01::parse("1\n\n-5\n").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 1));
+    assert_eq!(e.message(), "expected calorie count, found `-5`");
+}
@@ -0,0 +1,133 @@
+//! Every parser returns an error instead of panicking on malformed input
+//!
+//! This is a quick stand-in for the fuzz targets under `fuzz/`: the inputs,
+//! examples and regression seeds of `fuzz/seeds` are mutated with a fixed
+//! seed and fed to the parser of their day. Set `AOC_MUTATIONS` to try more
+//! inputs per file.
+
+use advent_of_code_2022::days::{self, day13};
+use advent_of_code_2022::solution::{Answer, Solution};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+const DEFAULT_MUTATIONS: usize = 300;
+
+/// Bytes that are meaningful to at least one parser
+const ALPHABET: &[u8] =
+    b"0123456789[],-+*= \n:$abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ/.";
+
+/// Deterministic xorshift64 generator
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n.max(1) as u64) as usize
+    }
+}
+
+/// Apply one random edit to `input`
+fn mutate(rng: &mut Rng, input: &mut Vec<u8>) {
+    let len = input.len();
+    let at = rng.below(len + 1);
+    match rng.below(6) {
+        0 => input.insert(at, ALPHABET[rng.below(ALPHABET.len())]),
+        1 if len > 0 => {
+            let end = (at + 1 + rng.below(8)).min(len);
+            input.drain(at.min(len - 1)..end);
+        }
+        2 if len > 0 => input[at.min(len - 1)] = ALPHABET[rng.below(ALPHABET.len())],
+        3 => input.truncate(at),
+        4 => {
+            // Repeat a slice, e.g. to nest brackets deeper
+            let end = (at + rng.below(16)).min(len);
+            let slice = input[at..end].to_vec();
+            input.splice(at..at, slice);
+        }
+        _ => {
+            let digits = format!("{}", u64::MAX - rng.below(1000) as u64);
+            input.splice(at..at, digits.into_bytes());
+        }
+    }
+}
+
+/// Example and real inputs and regression seeds of the day
+fn seeds(day: u8) -> Vec<Vec<u8>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut files = vec![root.join(format!("input/day{}.txt", day))];
+    for dir in ["input/examples", "fuzz/seeds"] {
+        for entry in fs::read_dir(root.join(dir)).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            if name == format!("day{}", day) || name.starts_with(&format!("day{}-", day)) {
+                files.push(path);
+            }
+        }
+    }
+    let mut seeds: Vec<Vec<u8>> = files.iter().filter_map(|f| fs::read(f).ok()).collect();
+    seeds.push(vec![]);
+    seeds
+}
+
+#[test]
+fn mutated_inputs_do_not_panic_the_parsers() {
+    let mutations = std::env::var("AOC_MUTATIONS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(DEFAULT_MUTATIONS);
+
+    for day in days::DAYS {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d ^ day.day as u64);
+        for seed in seeds(day.day) {
+            let mut input = seed.clone();
+            for i in 0..mutations {
+                // Restart from the seed now and then so that edits stay local
+                if i % 20 == 0 {
+                    input = seed.clone();
+                }
+                mutate(&mut rng, &mut input);
+                let text = String::from_utf8_lossy(&input);
+                // No parts are solved, so only the parser runs
+                let result = panic::catch_unwind(AssertUnwindSafe(|| (day.run)(&text, &[])));
+                assert!(
+                    result.is_ok(),
+                    "day {} parser panicked on input:\n{}",
+                    day.day,
+                    text
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn day11_divisor_overflow_is_an_error() {
+    let input = include_str!("../fuzz/seeds/day11-divisor-overflow.txt");
+    let day = days::DAYS.iter().find(|day| day.day == 11).unwrap();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (day.run)(input, &[])));
+    let e = result.expect("parser panicked").unwrap_err();
+    assert_eq!((e.line, e.column), (18, 22));
+    assert_eq!(
+        e.expected,
+        "divisor keeping the product of all divisors within u64"
+    );
+}
+
+#[test]
+fn day13_deep_nesting_is_an_error() {
+    let input = include_str!("../fuzz/seeds/day13-deep-nesting.txt");
+    let day = days::DAYS.iter().find(|day| day.day == 13).unwrap();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (day.run)(input, &[])));
+    let e = result.expect("parser panicked").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 257));
+    assert_eq!(e.expected, "number, lists nested at most 256 deep");
+
+    // The deepest lists allowed are still compared
+    let deepest = format!("{}{}", "[".repeat(256), "]".repeat(256));
+    let input = format!("{}\n[1]\n", deepest);
+    let parsed = day13::Day13::parse(&input).unwrap();
+    assert_eq!(day13::Day13::part1(&parsed), Answer::from(1));
+}
@@ -0,0 +1,42 @@
+//! Thread pool and summary table used by `aoc run --jobs`
+
+use advent_of_code_2022::pool;
+use advent_of_code_2022::table;
+
+#[test]
+fn results_keep_the_order_of_the_jobs() {
+    let jobs: Vec<u64> = (0..100).collect();
+    let results = pool::map(jobs, 4, |n| n * n);
+    let expected: Vec<Result<u64, String>> = (0..100).map(|n| Ok(n * n)).collect();
+    assert_eq!(results, expected);
+    assert!(pool::map(Vec::<u64>::new(), 4, |n| n).is_empty());
+}
+
+#[test]
+fn panicking_job_does_not_affect_the_others() {
+    let results = pool::map(vec![1, 0, 2], 2, |n: u32| {
+        if n == 0 {
+            panic!("division by zero");
+        }
+        10 / n
+    });
+    assert_eq!(
+        results,
+        vec![Ok(10), Err("division by zero".to_string()), Ok(5)]
+    );
+}
+
+#[test]
+fn table_columns_are_aligned() {
+    let rows = vec![
+        vec!["1".to_string(), "72017".to_string(), "ok".to_string()],
+        vec!["12".to_string(), "".to_string(), "error".to_string()],
+    ];
+    assert_eq!(
+        table::render(&["Day", "Answer", "Status"], &rows),
+        "Day  Answer  Status\n\
+         -------------------\n\
+         1    72017   ok\n\
+         12           error\n"
+    );
+}
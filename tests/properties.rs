@@ -0,0 +1,1095 @@
+//! Property tests of the parsers and core algorithms
+//!
+//! Random valid inputs are rendered to the puzzle text format, parsed back and
+//! checked for round-trip equality and invariants of the solutions. Failures
+//! report the seed of the case; rerun it alone with `AOC_PROP_SEED=<seed>`.
+//! `AOC_PROP_CASES` changes the number of cases per property.
+
+use advent_of_code_2022::days::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+};
+use advent_of_code_2022::geom::{Direction, Point};
+use advent_of_code_2022::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+const DEFAULT_CASES: u64 = 200;
+
+/// Deterministic xorshift64* generator
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Number in `min..=max`
+    fn range(&mut self, min: u64, max: u64) -> u64 {
+        min + self.next() % (max - min + 1)
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+}
+
+/// Check `property` against generated values
+fn check<T: Debug>(generate: fn(&mut Rng) -> T, property: impl Fn(&T)) {
+    let env = |name| std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok());
+    let seeds: Vec<u64> = match env("AOC_PROP_SEED") {
+        Some(seed) => vec![seed],
+        None => (0..env("AOC_PROP_CASES").unwrap_or(DEFAULT_CASES)).collect(),
+    };
+    for seed in seeds {
+        let value = generate(&mut Rng::new(seed));
+        if let Err(e) = panic::catch_unwind(AssertUnwindSafe(|| property(&value))) {
+            eprintln!("property failed for AOC_PROP_SEED={}: {:?}", seed, value);
+            panic::resume_unwind(e);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 1
+////////////////////////////////////////////////////////////////////////////////
+
+fn elves(rng: &mut Rng) -> Vec<Vec<i32>> {
+    (0..rng.range(1, 20))
+        .map(|_| {
+            (0..rng.range(1, 6))
+                .map(|_| rng.range(0, 100_000) as i32)
+                .collect()
+        })
+        .collect()
+}
+
+fn render_elves(elves: &[Vec<i32>]) -> String {
+    let groups: Vec<String> = elves
+        .iter()
+        .map(|items| items.iter().map(|n| format!("{}\n", n)).collect())
+        .collect();
+    groups.join("\n")
+}
+
+#[test]
+fn day01_totals_match_the_groups() {
+    check(elves, |elves| {
+        let input = render_elves(elves);
+        let mut expected: Vec<u64> = elves
+            .iter()
+            .map(|items| items.iter().map(|&n| n as u64).sum())
+            .collect();
+        assert_eq!(day01::totals::<u64>(&input).unwrap(), expected);
+
+        expected.sort_unstable_by(|a, b| b.cmp(a));
+        expected.truncate(3);
+        let top3 = day01::Day01::parse(&input).unwrap();
+        assert_eq!(top3, expected);
+        assert_eq!(day01::Day01::part1(&top3), Answer::from(expected[0]));
+        let sum: u64 = expected.iter().sum();
+        assert_eq!(day01::Day01::part2(&top3), Answer::from(sum));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 2
+////////////////////////////////////////////////////////////////////////////////
+
+const XYZ: [(day02::Xyz, &str); 3] = [
+    (day02::Xyz::X, "X"),
+    (day02::Xyz::Y, "Y"),
+    (day02::Xyz::Z, "Z"),
+];
+
+/// Rounds as indices of the opponent hand and of the second column
+fn strategy_guide(rng: &mut Rng) -> Vec<(usize, usize)> {
+    (0..rng.range(1, 50))
+        .map(|_| (rng.range(0, 2) as usize, rng.range(0, 2) as usize))
+        .collect()
+}
+
+fn render_strategy_guide(rounds: &[(usize, usize)]) -> String {
+    rounds
+        .iter()
+        .map(|&(abc, xyz)| format!("{} {}\n", ["A", "B", "C"][abc], XYZ[xyz].1))
+        .collect()
+}
+
+#[test]
+fn day02_rounds_round_trip() {
+    check(strategy_guide, |rounds| {
+        let parsed = day02::Day02::parse(&render_strategy_guide(rounds)).unwrap();
+        let expected: Vec<_> = rounds
+            .iter()
+            .map(|&(abc, xyz)| (day02::Hand(abc), XYZ[xyz].0))
+            .collect();
+        assert_eq!(parsed, expected);
+    });
+}
+
+#[test]
+fn day02_scores_match_the_puzzle_rules() {
+    check(strategy_guide, |rounds| {
+        // Outcomes 0, 1 and 2 are losing, a draw and winning
+        let outcome = |own: usize, other: usize| (own + 4 - other) % 3;
+        let score = |own: usize, outcome: usize| (own + 1 + 3 * outcome) as i64;
+        let part1: i64 = rounds
+            .iter()
+            .map(|&(other, own)| score(own, outcome(own, other)))
+            .sum();
+        let part2: i64 = rounds
+            .iter()
+            .map(|&(other, outcome)| score((other + outcome + 2) % 3, outcome))
+            .sum();
+
+        let parsed = day02::Day02::parse(&render_strategy_guide(rounds)).unwrap();
+        assert_eq!(day02::Day02::part1(&parsed), Answer::from(part1));
+        assert_eq!(day02::Day02::part2(&parsed), Answer::from(part2));
+
+        // Reading the letters as hands never beats the best permutation
+        let rules = day02::Rules::rock_paper_scissors();
+        let ranking = day02::rank_hand_tables(&rules, &parsed);
+        assert!(ranking[0].1 >= part1);
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 3
+////////////////////////////////////////////////////////////////////////////////
+
+/// Groups of three rucksacks with few different items so that they share some
+fn rucksacks(rng: &mut Rng) -> Vec<String> {
+    let items: Vec<char> = "abcdeVWXYZ".chars().collect();
+    (0..rng.range(1, 5) * 3)
+        .map(|_| (0..rng.range(1, 8) * 2).map(|_| rng.pick(&items)).collect())
+        .collect()
+}
+
+fn priority(item: char) -> i64 {
+    match item {
+        'a'..='z' => item as i64 - 'a' as i64 + 1,
+        _ => item as i64 - 'A' as i64 + 27,
+    }
+}
+
+#[test]
+fn day03_rucksacks_round_trip() {
+    check(rucksacks, |rucksacks| {
+        let parsed = day03::Day03::parse(&(rucksacks.join("\n") + "\n")).unwrap();
+        assert_eq!(&parsed, rucksacks);
+    });
+}
+
+#[test]
+fn day03_priorities_match_shared_items() {
+    check(rucksacks, |rucksacks| {
+        // The first item of the first compartment or rucksack that is shared
+        let part1: i64 = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let (left, right) = rucksack.split_at(rucksack.len() / 2);
+                left.chars()
+                    .find(|&item| right.contains(item))
+                    .map_or(0, priority)
+            })
+            .sum();
+        let part2: i64 = rucksacks
+            .chunks(3)
+            .map(|group| {
+                group[0]
+                    .chars()
+                    .find(|&item| group[1].contains(item) && group[2].contains(item))
+                    .map_or(0, priority)
+            })
+            .sum();
+
+        let parsed = day03::Day03::parse(&(rucksacks.join("\n") + "\n")).unwrap();
+        assert_eq!(day03::Day03::part1(&parsed), Answer::from(part1));
+        assert_eq!(day03::Day03::part2(&parsed), Answer::from(part2));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 4
+////////////////////////////////////////////////////////////////////////////////
+
+type Sections = (u32, u32);
+
+fn assignments(rng: &mut Rng) -> Vec<(Sections, Sections)> {
+    let range = |rng: &mut Rng| {
+        let min = rng.range(1, 99) as u32;
+        (min, rng.range(min as u64, 99) as u32)
+    };
+    (0..rng.range(1, 30))
+        .map(|_| (range(rng), range(rng)))
+        .collect()
+}
+
+fn render_assignments(pairs: &[(Sections, Sections)]) -> String {
+    pairs
+        .iter()
+        .map(|((a, b), (c, d))| format!("{}-{},{}-{}\n", a, b, c, d))
+        .collect()
+}
+
+#[test]
+fn day04_ranges_round_trip() {
+    check(assignments, |pairs| {
+        let text = render_assignments(pairs);
+        let parsed = day04::Day04::parse(&text).unwrap();
+        let rendered: String = parsed
+            .iter()
+            .map(|(left, right)| format!("{},{}\n", left, right))
+            .collect();
+        assert_eq!(rendered, text);
+    });
+}
+
+#[test]
+fn day04_counts_match_section_sets() {
+    check(assignments, |pairs| {
+        let sets = |(min, max): Sections| (min..=max).collect::<HashSet<u32>>();
+        let (mut contained, mut overlapping) = (0, 0);
+        for (left, right) in pairs {
+            let (left, right) = (sets(*left), sets(*right));
+            if left.is_subset(&right) || right.is_subset(&left) {
+                contained += 1;
+            }
+            if !left.is_disjoint(&right) {
+                overlapping += 1;
+            }
+        }
+        assert!(contained <= overlapping);
+
+        let parsed = day04::Day04::parse(&render_assignments(pairs)).unwrap();
+        assert_eq!(day04::Day04::part1(&parsed), Answer::from(contained));
+        assert_eq!(day04::Day04::part2(&parsed), Answer::from(overlapping));
+
+        // Swapping the elves of each pair changes nothing
+        let swapped: Vec<_> = pairs.iter().map(|(a, b)| (*b, *a)).collect();
+        let swapped = day04::Day04::parse(&render_assignments(&swapped)).unwrap();
+        assert_eq!(day04::Day04::part1(&swapped), Answer::from(contained));
+        assert_eq!(day04::Day04::part2(&swapped), Answer::from(overlapping));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 5
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+struct Supplies {
+    stacks: Vec<Vec<char>>,
+    /// `(amount, from, to)` with 1-based stack numbers
+    moves: Vec<(usize, usize, usize)>,
+}
+
+/// Stacks and moves that never take more crates than a stack holds
+fn supplies(rng: &mut Rng) -> Supplies {
+    let letters: Vec<char> = ('A'..='Z').collect();
+    // The layout has room for stack numbers of up to two digits
+    let stacks: Vec<Vec<char>> = (0..rng.range(1, 99))
+        .map(|_| (0..rng.range(0, 6)).map(|_| rng.pick(&letters)).collect())
+        .collect();
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let mut moves = vec![];
+    for _ in 0..rng.range(0, 12) {
+        let sources: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+        if sources.is_empty() || heights.len() < 2 {
+            break;
+        }
+        let from = rng.pick(&sources);
+        let mut to = rng.range(0, heights.len() as u64 - 2) as usize;
+        if to >= from {
+            to += 1;
+        }
+        let amount = rng.range(1, heights[from] as u64) as usize;
+        heights[from] -= amount;
+        heights[to] += amount;
+        moves.push((amount, from + 1, to + 1));
+    }
+    Supplies { stacks, moves }
+}
+
+fn render_supplies(supplies: &Supplies) -> String {
+    let mut text = String::new();
+    let height = supplies.stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for row in (0..height).rev() {
+        let cells: Vec<String> = supplies
+            .stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        text += &(cells.join(" ") + "\n");
+    }
+    let numbers: Vec<String> = (1..=supplies.stacks.len())
+        .map(|i| format!(" {:<2}", i))
+        .collect();
+    text += &(numbers.join(" ") + "\n\n");
+    for (amount, from, to) in &supplies.moves {
+        text += &format!("move {} from {} to {}\n", amount, from, to);
+    }
+    text
+}
+
+/// Top crates after the moves, moving crates one by one or all at once
+fn top_crates(supplies: &Supplies, one_by_one: bool) -> String {
+    let mut stacks = supplies.stacks.clone();
+    for &(amount, from, to) in &supplies.moves {
+        let start = stacks[from - 1].len() - amount;
+        let mut moved = stacks[from - 1].split_off(start);
+        if one_by_one {
+            moved.reverse();
+        }
+        stacks[to - 1].extend(moved);
+    }
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+#[test]
+fn day05_layout_round_trips() {
+    check(supplies, |supplies| {
+        let text = render_supplies(supplies);
+        let parsed = day05::Day05::parse(&text).unwrap();
+        assert_eq!(parsed.to_string(), text);
+    });
+}
+
+#[test]
+fn day05_cranes_match_a_model() {
+    check(supplies, |supplies| {
+        let parsed = day05::Day05::parse(&render_supplies(supplies)).unwrap();
+        let part1 = top_crates(supplies, true);
+        let part2 = top_crates(supplies, false);
+        assert_eq!(day05::Day05::part1(&parsed), Answer::from(part1.as_str()));
+        assert_eq!(day05::Day05::part2(&parsed), Answer::from(part2.as_str()));
+        if supplies.moves.iter().all(|(amount, _, _)| *amount == 1) {
+            assert_eq!(part1, part2);
+        }
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 6
+////////////////////////////////////////////////////////////////////////////////
+
+/// Datastream with few different letters that ends in a start-of-message
+/// marker, so that both markers exist
+fn datastream(rng: &mut Rng) -> String {
+    let letters: Vec<char> = "abcdef".chars().collect();
+    let noise: String = (0..rng.range(0, 100)).map(|_| rng.pick(&letters)).collect();
+    noise + "ghijklmnopqrst"
+}
+
+/// Number of characters up to the first `len` different ones in a row
+fn marker(datastream: &str, len: usize) -> usize {
+    let bytes = datastream.as_bytes();
+    (len..=bytes.len())
+        .find(|&end| bytes[end - len..end].iter().collect::<HashSet<_>>().len() == len)
+        .unwrap()
+}
+
+#[test]
+fn day06_datastream_round_trips() {
+    check(datastream, |datastream| {
+        let parsed = day06::Day06::parse(&format!("{}\n", datastream)).unwrap();
+        assert_eq!(&parsed, datastream);
+    });
+}
+
+#[test]
+fn day06_markers_are_the_first_distinct_windows() {
+    check(datastream, |datastream| {
+        let parsed = day06::Day06::parse(datastream).unwrap();
+        let (packet, message) = (marker(datastream, 4), marker(datastream, 14));
+        assert_eq!(day06::Day06::part1(&parsed), Answer::from(packet));
+        assert_eq!(day06::Day06::part2(&parsed), Answer::from(message));
+        // A start-of-message marker contains a start-of-packet marker
+        assert!(packet <= message);
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 7
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+struct Dir {
+    name: String,
+    dirs: Vec<Dir>,
+    /// Names and sizes, at least one so that no directory is empty
+    files: Vec<(String, u32)>,
+}
+
+impl Dir {
+    fn size(&self) -> u64 {
+        let files: u64 = self.files.iter().map(|(_, size)| *size as u64).sum();
+        files + self.dirs.iter().map(Dir::size).sum::<u64>()
+    }
+
+    /// Sizes of this directory and all directories below it
+    fn sizes(&self, sizes: &mut Vec<u64>) {
+        sizes.push(self.size());
+        for dir in &self.dirs {
+            dir.sizes(sizes);
+        }
+    }
+}
+
+/// Directory tree with both small and large files, so that the total is
+/// sometimes above and sometimes below the space needed for the update
+fn dir_tree(rng: &mut Rng) -> Dir {
+    fn dir(rng: &mut Rng, name: String, depth: u32) -> Dir {
+        let dirs = match depth {
+            3 => 0,
+            _ => rng.range(0, 3),
+        };
+        Dir {
+            dirs: (0..dirs)
+                .map(|i| dir(rng, format!("d{}", i), depth + 1))
+                .collect(),
+            files: (0..rng.range(1, 3))
+                .map(|i| {
+                    let size = match rng.range(0, 1) {
+                        0 => rng.range(1, 50_000),
+                        _ => rng.range(1, 10_000_000),
+                    };
+                    (format!("f{}.txt", i), size as u32)
+                })
+                .collect(),
+            name,
+        }
+    }
+    dir(rng, "/".to_string(), 0)
+}
+
+/// Terminal output listing every directory once, entering them in order
+fn render_terminal(root: &Dir) -> String {
+    fn list(dir: &Dir, out: &mut String) {
+        *out += "$ ls\n";
+        for sub in &dir.dirs {
+            *out += &format!("dir {}\n", sub.name);
+        }
+        for (name, size) in &dir.files {
+            *out += &format!("{} {}\n", size, name);
+        }
+        for sub in &dir.dirs {
+            *out += &format!("$ cd {}\n", sub.name);
+            list(sub, out);
+            *out += "$ cd ..\n";
+        }
+    }
+    let mut out = "$ cd /\n".to_string();
+    list(root, &mut out);
+    out
+}
+
+/// Tree as drawn by the `tree` command of the REPL
+fn draw_tree(dir: &Dir, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    *out += &format!("{}- {} (dir)\n", indent, dir.name);
+    for sub in &dir.dirs {
+        draw_tree(sub, depth + 1, out);
+    }
+    for (name, size) in &dir.files {
+        *out += &format!("{}  - {} (file, size={})\n", indent, name, size);
+    }
+}
+
+#[test]
+fn day07_tree_round_trips() {
+    check(dir_tree, |root| {
+        let mut explorer = day07::explore(&render_terminal(root)).unwrap();
+        let mut drawing = String::new();
+        draw_tree(root, 0, &mut drawing);
+        assert_eq!(explorer.execute("tree", &[]).unwrap(), drawing);
+        assert_eq!(
+            explorer.execute("du", &[]).unwrap(),
+            format!("{}\n", root.size())
+        );
+    });
+}
+
+#[test]
+fn day07_sizes_match_the_tree() {
+    check(dir_tree, |root| {
+        let mut sizes = vec![];
+        root.sizes(&mut sizes);
+        let small: u64 = sizes.iter().filter(|&&size| size <= 100_000).sum();
+        let needed = 30_000_000u64.saturating_sub(70_000_000u64.saturating_sub(root.size()));
+        let freed = sizes.iter().filter(|&&size| size >= needed).min().unwrap();
+
+        let parsed = day07::Day07::parse(&render_terminal(root)).unwrap();
+        assert_eq!(day07::Day07::part1(&parsed), Answer::from(small));
+        assert_eq!(day07::Day07::part2(&parsed), Answer::from(*freed));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 8
+////////////////////////////////////////////////////////////////////////////////
+
+fn tree_heights(rng: &mut Rng) -> Vec<Vec<u32>> {
+    let width = rng.range(1, 8);
+    (0..rng.range(1, 8))
+        .map(|_| (0..width).map(|_| rng.range(0, 9) as u32).collect())
+        .collect()
+}
+
+fn render_tree_heights(rows: &[Vec<u32>]) -> String {
+    rows.iter()
+        .map(|row| row.iter().map(u32::to_string).collect::<String>() + "\n")
+        .collect()
+}
+
+/// Heights of the trees seen from `(x, y)` towards each edge, nearest first
+fn lines_of_sight(rows: &[Vec<u32>], x: usize, y: usize) -> [Vec<u32>; 4] {
+    let column: Vec<u32> = rows.iter().map(|row| row[x]).collect();
+    [
+        column[..y].iter().rev().copied().collect(),
+        column[y + 1..].to_vec(),
+        rows[y][..x].iter().rev().copied().collect(),
+        rows[y][x + 1..].to_vec(),
+    ]
+}
+
+#[test]
+fn day08_grid_round_trips() {
+    check(tree_heights, |rows| {
+        let grid = day08::Day08::parse(&render_tree_heights(rows)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (rows[0].len(), rows.len()));
+        for (y, row) in rows.iter().enumerate() {
+            for (x, height) in row.iter().enumerate() {
+                assert_eq!(grid.get(&Point::new(x, y)), Some(height));
+            }
+        }
+    });
+}
+
+#[test]
+fn day08_visibility_matches_lines_of_sight() {
+    check(tree_heights, |rows| {
+        let (mut visible, mut best) = (0, 0);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &height) in row.iter().enumerate() {
+                let sights = lines_of_sight(rows, x, y);
+                if sights.iter().any(|sight| sight.iter().all(|&h| h < height)) {
+                    visible += 1;
+                }
+                let score: usize = sights
+                    .iter()
+                    .map(|sight| match sight.iter().position(|&h| h >= height) {
+                        Some(i) => i + 1,
+                        None => sight.len(),
+                    })
+                    .product();
+                best = best.max(score);
+            }
+        }
+        // The trees on the edges are always visible
+        let (width, height) = (rows[0].len(), rows.len());
+        assert!(visible >= (2 * (width + height)).saturating_sub(4).min(width * height));
+
+        let grid = day08::Day08::parse(&render_tree_heights(rows)).unwrap();
+        assert_eq!(day08::Day08::part1(&grid), Answer::from(visible));
+        assert_eq!(day08::Day08::part2(&grid), Answer::from(best));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 9
+////////////////////////////////////////////////////////////////////////////////
+
+const DIRECTIONS: [(Direction, &str); 4] = [
+    (Direction::Up, "U"),
+    (Direction::Down, "D"),
+    (Direction::Left, "L"),
+    (Direction::Right, "R"),
+];
+
+fn motions(rng: &mut Rng) -> Vec<(Direction, i32)> {
+    (0..rng.range(1, 40))
+        .map(|_| (rng.pick(&DIRECTIONS).0, rng.range(1, 10) as i32))
+        .collect()
+}
+
+fn render_motions(motions: &[(Direction, i32)]) -> String {
+    motions
+        .iter()
+        .map(|(dir, steps)| {
+            let letter = DIRECTIONS.iter().find(|(d, _)| d == dir).unwrap().1;
+            format!("{} {}\n", letter, steps)
+        })
+        .collect()
+}
+
+#[test]
+fn day09_motions_round_trip() {
+    check(motions, |motions| {
+        let parsed = day09::Day09::parse(&render_motions(motions)).unwrap();
+        assert_eq!(&parsed, motions);
+    });
+}
+
+#[test]
+fn day09_knots_stay_adjacent() {
+    check(motions, |motions| {
+        for len in [2, 3, 10] {
+            let mut rope = day09::Rope::new(len, Point::ZERO);
+            for &(dir, steps) in motions {
+                for _ in 0..steps {
+                    let head = rope.knots()[0].step(dir);
+                    rope.step(dir);
+                    assert_eq!(rope.knots()[0], head);
+                    for pair in rope.knots().windows(2) {
+                        let (a, b) = (pair[0], pair[1]);
+                        assert!(
+                            (a.x - b.x).abs() <= 1 && (a.y - b.y).abs() <= 1,
+                            "{:?} and {:?} are not adjacent",
+                            a,
+                            b
+                        );
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn day09_part1_matches_two_knot_rope() {
+    check(motions, |motions| {
+        let mut rope = day09::Rope::new(2, Point::ZERO);
+        let mut visited = HashSet::from([rope.tail()]);
+        for &(dir, steps) in motions {
+            for _ in 0..steps {
+                rope.step(dir);
+                visited.insert(rope.tail());
+            }
+        }
+        let parsed = day09::Day09::parse(&render_motions(motions)).unwrap();
+        assert_eq!(day09::Day09::part1(&parsed), Answer::from(visited.len()));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 10
+////////////////////////////////////////////////////////////////////////////////
+
+fn program(rng: &mut Rng) -> Vec<day10::Op> {
+    (0..rng.range(1, 150))
+        .map(|_| match rng.range(0, 1) {
+            0 => day10::Op::Noop,
+            _ => day10::Op::Addx(rng.range(0, 20) as i32 - 10),
+        })
+        .collect()
+}
+
+fn render_program(ops: &[day10::Op]) -> String {
+    ops.iter()
+        .map(|op| match op {
+            day10::Op::Noop => "noop\n".to_string(),
+            day10::Op::Addx(value) => format!("addx {}\n", value),
+        })
+        .collect()
+}
+
+/// Value of the register during each cycle, `None` if it leaves `i32`
+fn register_values(ops: &[day10::Op]) -> Option<Vec<i64>> {
+    let mut x = 1i64;
+    let mut values = vec![];
+    for op in ops {
+        match op {
+            day10::Op::Noop => values.push(x),
+            day10::Op::Addx(value) => {
+                values.extend([x, x]);
+                x += *value as i64;
+                i32::try_from(x).ok()?;
+            }
+        }
+    }
+    Some(values)
+}
+
+/// Check both parts against the register values, or that a program leaving
+/// `i32` is rejected
+fn check_program(ops: &[day10::Op]) {
+    let text = render_program(ops);
+    let Some(values) = register_values(ops) else {
+        let e = day10::Day10::parse(&text).err().unwrap();
+        assert_eq!(e.expected, "number keeping the register within i32");
+        return;
+    };
+    let signal: i128 = (1..=values.len())
+        .filter(|cycle| cycle % 40 == 20)
+        .map(|cycle| cycle as i128 * values[cycle - 1] as i128)
+        .sum();
+    let mut image = String::new();
+    for (i, x) in values.iter().enumerate() {
+        let column = (i % 40) as i64;
+        image.push(if (x - column).abs() <= 1 { '#' } else { ' ' });
+        if column == 39 {
+            image.push('\n');
+        }
+    }
+
+    let parsed = day10::Day10::parse(&text).unwrap();
+    assert_eq!(day10::Day10::part1(&parsed), Answer::Number(signal));
+    assert_eq!(day10::Day10::part2(&parsed), Answer::from(image));
+}
+
+#[test]
+fn day10_program_round_trips() {
+    check(program, |ops| {
+        let parsed = day10::Day10::parse(&render_program(ops)).unwrap();
+        assert_eq!(&parsed, ops);
+    });
+}
+
+#[test]
+fn day10_signal_and_image_match_the_register() {
+    check(program, |ops| check_program(ops));
+}
+
+#[test]
+fn day10_extreme_values_are_rejected_or_solved() {
+    let extreme = |rng: &mut Rng| -> Vec<day10::Op> {
+        let values = [i32::MIN, i32::MIN + 2, -1, 1, i32::MAX - 1, i32::MAX];
+        (0..rng.range(1, 150))
+            .map(|_| match rng.range(0, 2) {
+                0 => day10::Op::Noop,
+                _ => day10::Op::Addx(rng.pick(&values)),
+            })
+            .collect()
+    };
+    check(extreme, |ops| check_program(ops));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 11
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+struct MonkeyModel {
+    items: Vec<u64>,
+    /// `None` squares the worry level
+    add: Option<u64>,
+    multiply: Option<u64>,
+    divisor: u64,
+    /// Monkeys thrown to when the test is true and false
+    targets: (usize, usize),
+}
+
+impl MonkeyModel {
+    fn inspect(&self, worry_level: u64) -> u128 {
+        let worry_level = worry_level as u128;
+        match (self.add, self.multiply) {
+            (Some(n), _) => worry_level + n as u128,
+            (_, Some(n)) => worry_level * n as u128,
+            _ => worry_level * worry_level,
+        }
+    }
+}
+
+fn troop(rng: &mut Rng) -> Vec<MonkeyModel> {
+    let count = rng.range(2, 5) as usize;
+    let other = |rng: &mut Rng, id: usize| (id + rng.range(1, count as u64 - 1) as usize) % count;
+    (0..count)
+        .map(|id| {
+            let n = rng.range(1, 9);
+            let (add, multiply) = match rng.range(0, 4) {
+                0 => (None, None),
+                1 | 2 => (Some(n), None),
+                _ => (None, Some(n)),
+            };
+            MonkeyModel {
+                items: (0..rng.range(1, 4)).map(|_| rng.range(1, 99)).collect(),
+                add,
+                multiply,
+                divisor: rng.pick(&[2, 3, 5, 7, 11, 13, 17, 19, 23]),
+                targets: (other(rng, id), other(rng, id)),
+            }
+        })
+        .collect()
+}
+
+fn render_troop(monkeys: &[MonkeyModel]) -> String {
+    let descriptions: Vec<String> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(id, monkey)| {
+            let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+            let operation = match (monkey.add, monkey.multiply) {
+                (Some(n), _) => format!("+ {}", n),
+                (_, Some(n)) => format!("* {}", n),
+                _ => "* old".to_string(),
+            };
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                id,
+                items.join(", "),
+                operation,
+                monkey.divisor,
+                monkey.targets.0,
+                monkey.targets.1
+            )
+        })
+        .collect();
+    descriptions.join("\n")
+}
+
+/// Monkey business after `rounds` rounds with the `relief` of a part
+fn monkey_business(monkeys: &[MonkeyModel], rounds: usize, relief: impl Fn(u128) -> u64) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for id in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[id].items) {
+                inspections[id] += 1;
+                let worry_level = relief(monkeys[id].inspect(item));
+                let (yes, no) = monkeys[id].targets;
+                let target = if worry_level.is_multiple_of(monkeys[id].divisor) {
+                    yes
+                } else {
+                    no
+                };
+                monkeys[target].items.push(worry_level);
+            }
+        }
+    }
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    inspections[0] * inspections[1]
+}
+
+#[test]
+fn day11_monkeys_round_trip() {
+    check(troop, |monkeys| {
+        let mut explorer = day11::explore(&render_troop(monkeys)).unwrap();
+        let mut expected = "after round 0:\n".to_string();
+        for (id, monkey) in monkeys.iter().enumerate() {
+            let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+            expected += &format!(
+                "Monkey {} (inspected items 0 times): {}\n",
+                id,
+                items.join(", ")
+            );
+        }
+        assert_eq!(explorer.execute("monkeys", &[]).unwrap(), expected);
+    });
+}
+
+#[test]
+fn day11_monkey_business_matches_a_model() {
+    check(troop, |monkeys| {
+        // Worry levels saturate in part 1 when squaring makes them too large
+        let part1 = monkey_business(monkeys, 20, |worry_level| {
+            u64::try_from(worry_level / 3).unwrap_or(u64::MAX)
+        });
+        let product: u64 = monkeys.iter().map(|monkey| monkey.divisor).product();
+        let part2 = monkey_business(monkeys, 10_000, |worry_level| {
+            (worry_level % product as u128) as u64
+        });
+
+        let parsed = day11::Day11::parse(&render_troop(monkeys)).unwrap();
+        assert_eq!(day11::Day11::part1(&parsed), Answer::from(part1));
+        assert_eq!(day11::Day11::part2(&parsed), Answer::from(part2));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 12
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+struct Hill {
+    heights: Vec<Vec<u8>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+/// Heightmap rising towards the end by a random slope, so that the end is
+/// reachable in some cases and not in others
+fn hill(rng: &mut Rng) -> Hill {
+    let (width, height) = (rng.range(2, 8) as usize, rng.range(1, 6) as usize);
+    let cell = |rng: &mut Rng| {
+        (
+            rng.range(0, width as u64 - 1) as usize,
+            rng.range(0, height as u64 - 1) as usize,
+        )
+    };
+    let end = cell(rng);
+    let mut start = cell(rng);
+    while start == end {
+        start = cell(rng);
+    }
+    let slope = rng.range(1, 4) as i64;
+    let heights = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let distance = (x.abs_diff(end.0) + y.abs_diff(end.1)) as i64;
+                    let noise = rng.range(0, 2) as i64 - 1;
+                    (25 - slope * distance + noise).clamp(0, 25) as u8
+                })
+                .collect()
+        })
+        .collect();
+    Hill {
+        heights,
+        start,
+        end,
+    }
+}
+
+fn render_hill(hill: &Hill) -> String {
+    let mut text = String::new();
+    for (y, row) in hill.heights.iter().enumerate() {
+        for (x, &height) in row.iter().enumerate() {
+            text.push(match (x, y) {
+                p if p == hill.start => 'S',
+                p if p == hill.end => 'E',
+                _ => (b'a' + height) as char,
+            });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Fewest steps from every square to the end, `None` where it is unreachable
+fn steps_to_end(hill: &Hill) -> Vec<Vec<Option<usize>>> {
+    let mut heights = hill.heights.clone();
+    heights[hill.start.1][hill.start.0] = 0;
+    heights[hill.end.1][hill.end.0] = 25;
+    let (width, height) = (heights[0].len(), heights.len());
+    let mut steps = vec![vec![None; width]; height];
+    steps[hill.end.1][hill.end.0] = Some(0);
+    let mut queue = std::collections::VecDeque::from([hill.end]);
+    while let Some((x, y)) = queue.pop_front() {
+        let next = steps[y][x].unwrap() + 1;
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in neighbors {
+            if nx >= width || ny >= height || steps[ny][nx].is_some() {
+                continue;
+            }
+            // A step from the neighbor climbs at most one
+            if heights[y][x] <= heights[ny][nx] + 1 {
+                steps[ny][nx] = Some(next);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    steps
+}
+
+#[test]
+fn day12_paths_match_a_search_from_the_end() {
+    check(hill, |hill| {
+        let steps = steps_to_end(hill);
+        let text = render_hill(hill);
+        let part1 = steps[hill.start.1][hill.start.0];
+        let lowest = hill.heights.iter().zip(&steps).flat_map(|(row, steps)| {
+            row.iter()
+                .zip(steps)
+                .filter(|(&height, _)| height == 0)
+                .filter_map(|(_, steps)| *steps)
+        });
+        let part2 = lowest.chain(part1).min();
+
+        let answer =
+            |steps: Option<usize>| steps.map_or(Answer::from(day12::NO_PATH), Answer::from);
+        let parsed = day12::Day12::parse(&text).unwrap();
+        assert_eq!(day12::Day12::part1(&parsed), answer(part1));
+        assert_eq!(day12::Day12::part2(&parsed), answer(part2));
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Day 13
+////////////////////////////////////////////////////////////////////////////////
+
+/// Packet text with small numbers and nesting so that ties are common
+fn packet(rng: &mut Rng, depth: u32) -> String {
+    let items: Vec<String> = (0..rng.range(0, 4))
+        .map(|_| {
+            if depth < 3 && rng.range(0, 2) == 0 {
+                packet(rng, depth + 1)
+            } else {
+                rng.range(0, 10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+fn packets(rng: &mut Rng) -> Vec<String> {
+    (0..3).map(|_| packet(rng, 0)).collect()
+}
+
+fn parse_packets(packets: &[String]) -> Vec<day13::ElementList> {
+    let pairs: Vec<String> = packets
+        .chunks(2)
+        .map(|pair| format!("{}\n{}\n", pair[0], pair.get(1).unwrap_or(&pair[0])))
+        .collect();
+    let parsed = day13::Day13::parse(&pairs.join("\n")).unwrap();
+    parsed
+        .into_iter()
+        .flat_map(|(left, right)| [left, right])
+        .take(packets.len())
+        .collect()
+}
+
+#[test]
+fn day13_packets_round_trip() {
+    check(packets, |packets| {
+        let parsed = parse_packets(packets);
+        let rendered: Vec<String> = parsed.iter().map(ToString::to_string).collect();
+        assert_eq!(&rendered, packets);
+    });
+}
+
+#[test]
+fn day13_packet_order_is_total() {
+    check(packets, |packets| {
+        let parsed = parse_packets(packets);
+        let (a, b, c) = (&parsed[0], &parsed[1], &parsed[2]);
+        for x in [a, b, c] {
+            assert_eq!(x.cmp(x), Ordering::Equal);
+            for y in [a, b, c] {
+                assert_eq!(x.cmp(y), y.cmp(x).reverse(), "{} vs {}", x, y);
+            }
+        }
+        for (x, y, z) in [
+            (a, b, c),
+            (a, c, b),
+            (b, a, c),
+            (b, c, a),
+            (c, a, b),
+            (c, b, a),
+        ] {
+            if x <= y && y <= z {
+                assert!(x <= z, "{} <= {} <= {} but not {} <= {}", x, y, z, x, z);
+            }
+        }
+    });
+}
@@ -0,0 +1,100 @@
+//! Commands of `aoc repl` on the puzzle examples
+
+use advent_of_code_2022::days;
+use advent_of_code_2022::repl::{Reply, Session};
+use std::fs;
+use std::path::Path;
+
+fn open(day: u8) -> Session {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("input/examples/day{}.txt", day));
+    Session::open(days::get(day).unwrap(), fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn eval(session: &mut Session, line: &str) -> String {
+    match session.eval(line) {
+        Ok(Reply::Output(output)) => output,
+        other => panic!("`{}` returned {:?}", line, other),
+    }
+}
+
+#[test]
+fn builtin_commands_work_for_every_day() {
+    let mut session = open(1);
+    assert_eq!(
+        eval(&mut session, "solve"),
+        "Part 1: 24000\nPart 2: 45000\n"
+    );
+    assert_eq!(eval(&mut session, "solve 2"), "Part 2: 45000\n");
+    assert_eq!(eval(&mut session, "  "), "");
+    assert!(eval(&mut session, "help").starts_with("solve [1|2]"));
+    assert_eq!(
+        session.eval("tree"),
+        Err("unknown command `tree`, try `help`".to_string())
+    );
+    assert_eq!(session.eval("solve 3"), Err("invalid part `3`".to_string()));
+    assert_eq!(session.eval("exit"), Ok(Reply::Quit));
+}
+
+#[test]
+fn crate_stacks_are_stepped_through() {
+    let mut session = open(5);
+    assert!(eval(&mut session, "help").starts_with("stacks "));
+    assert_eq!(
+        eval(&mut session, "step 2"),
+        "move 1 from 2 to 1\n\
+         move 3 from 1 to 3\n\
+         after move 2 of 4:\n        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3 \n"
+    );
+    assert_eq!(
+        eval(&mut session, "goto 1"),
+        "after move 1 of 4:\n[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n"
+    );
+    assert_eq!(eval(&mut session, "goto 9"), eval(&mut session, "stacks"));
+    assert_eq!(eval(&mut session, "top"), "CMZ\n");
+    eval(&mut session, "crane 9001");
+    assert_eq!(eval(&mut session, "top"), "MCD\n");
+    assert_eq!(
+        session.eval("crane 9002"),
+        Err("unknown crane model 9002".to_string())
+    );
+}
+
+#[test]
+fn file_tree_is_browsed_by_path() {
+    let mut session = open(7);
+    assert_eq!(eval(&mut session, "du"), "48381165\n");
+    assert_eq!(eval(&mut session, "du /a"), "94853\n");
+    assert_eq!(eval(&mut session, "du /a/e/i"), "584\n");
+    assert_eq!(
+        eval(&mut session, "ls /a"),
+        "e (dir, size=584)\nf (file, size=29116)\ng (file, size=2557)\nh.lst (file, size=62596)\n"
+    );
+    assert_eq!(
+        eval(&mut session, "tree /a/e"),
+        "- e (dir)\n  - i (file, size=584)\n"
+    );
+    assert_eq!(
+        session.eval("ls /x"),
+        Err("no such file or directory `/x`".to_string())
+    );
+}
+
+#[test]
+fn monkeys_are_played_round_by_round() {
+    let mut session = open(11);
+    assert_eq!(
+        eval(&mut session, "round 1"),
+        "after round 1:\n\
+         Monkey 0 (inspected items 2 times): 20, 23, 27, 26\n\
+         Monkey 1 (inspected items 4 times): 2080, 25, 167, 207, 401, 1046\n\
+         Monkey 2 (inspected items 3 times):\n\
+         Monkey 3 (inspected items 5 times):\n"
+    );
+    eval(&mut session, "round 20");
+    assert_eq!(eval(&mut session, "business"), "10605\n");
+
+    eval(&mut session, "relief 2");
+    eval(&mut session, "next 9999");
+    eval(&mut session, "next");
+    assert_eq!(eval(&mut session, "business"), "2713310158\n");
+}
@@ -0,0 +1,181 @@
+//! Rules engine of day 2 and its variants with more hands
+
+use advent_of_code_2022::days::day02::{
+    self, rank_hand_tables, total_score, Day02, Decoder, Hand, HandTable, Outcome, OutcomeTable,
+    Rules,
+};
+use advent_of_code_2022::solution::Solution;
+use advent_of_code_2022::stats::{ReportFormat, ReportOptions};
+
+const EXAMPLE: &str = "A Y\nB X\nC Z\n";
+
+#[test]
+fn rock_paper_scissors_matches_the_puzzle() {
+    let rules = Rules::rock_paper_scissors();
+    assert_eq!(rules.outcome(Hand::PAPER, Hand::ROCK), Outcome::Win);
+    assert_eq!(rules.outcome(Hand::ROCK, Hand::SCISSORS), Outcome::Win);
+    assert_eq!(rules.outcome(Hand::SCISSORS, Hand::PAPER), Outcome::Win);
+    assert_eq!(rules.outcome(Hand::ROCK, Hand::PAPER), Outcome::Lose);
+    assert_eq!(rules.outcome(Hand::PAPER, Hand::PAPER), Outcome::Draw);
+
+    // Rounds of the example
+    assert_eq!(rules.score(Hand::PAPER, Hand::ROCK), 8);
+    assert_eq!(rules.score(Hand::ROCK, Hand::PAPER), 1);
+    assert_eq!(rules.score(Hand::SCISSORS, Hand::SCISSORS), 6);
+}
+
+#[test]
+fn every_hand_beats_half_of_the_others() {
+    for rules in [
+        Rules::rock_paper_scissors_lizard_spock(),
+        Rules::new(&["a", "b", "c", "d", "e", "f", "g"]).unwrap(),
+    ] {
+        let n = rules.hands();
+        for own in (0..n).map(Hand) {
+            let wins = (0..n)
+                .map(Hand)
+                .filter(|&other| rules.outcome(own, other) == Outcome::Win)
+                .collect::<Vec<_>>();
+            assert_eq!(wins.len(), (n - 1) / 2, "{}", rules.name(own));
+            for other in wins {
+                assert_eq!(rules.outcome(other, own), Outcome::Lose);
+            }
+        }
+    }
+
+    let rules = Rules::rock_paper_scissors_lizard_spock();
+    let hand = |name| (0..5).map(Hand).find(|&h| rules.name(h) == name).unwrap();
+    assert_eq!(rules.outcome(hand("Spock"), hand("scissors")), Outcome::Win);
+    assert_eq!(rules.outcome(hand("lizard"), hand("Spock")), Outcome::Win);
+    assert_eq!(rules.outcome(hand("rock"), hand("lizard")), Outcome::Win);
+    assert_eq!(rules.outcome(hand("paper"), hand("Spock")), Outcome::Win);
+    assert_eq!(
+        rules.outcome(hand("scissors"), hand("lizard")),
+        Outcome::Win
+    );
+}
+
+#[test]
+fn unbalanced_games_are_refused() {
+    for count in [0, 1, 2, 4, 6] {
+        let names = vec!["hand"; count];
+        let e = Rules::new(&names).unwrap_err();
+        assert!(e.contains(&format!("not {}", count)), "{}", e);
+    }
+    let e = Rules::rock_paper_scissors()
+        .with_hand_scores(&[1, 2])
+        .unwrap_err();
+    assert_eq!(e, "expected 3 hand scores, not 2");
+}
+
+#[test]
+fn hands_for_outcomes_follow_the_scores() {
+    let rules = Rules::rock_paper_scissors();
+    for other in (0..3).map(Hand) {
+        for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+            let own = rules.hand_for(other, outcome);
+            assert_eq!(rules.outcome(own, other), outcome);
+        }
+    }
+
+    // Rock and Spock both beat scissors; the higher score is preferred
+    let rules = Rules::rock_paper_scissors_lizard_spock();
+    let scissors = Hand(4);
+    assert_eq!(rules.hand_for(scissors, Outcome::Win), Hand(1));
+    let rules = rules.with_hand_scores(&[10, 1, 1, 1, 1]).unwrap();
+    assert_eq!(rules.hand_for(scissors, Outcome::Win), Hand(0));
+    // Equal scores pick the nearest hand in the cycle
+    let rules = rules.with_hand_scores(&[1; 5]).unwrap();
+    assert_eq!(rules.hand_for(scissors, Outcome::Win), Hand(0));
+    assert_eq!(rules.hand_for(scissors, Outcome::Lose), Hand(3));
+}
+
+#[test]
+fn outcome_scores_are_configurable() {
+    let rules = Rules::rock_paper_scissors()
+        .with_outcome_scores(-1, 0, 1)
+        .with_hand_scores(&[0, 0, 0])
+        .unwrap();
+    assert_eq!(rules.score(Hand::ROCK, Hand::SCISSORS), 1);
+    assert_eq!(rules.score(Hand::ROCK, Hand::ROCK), 0);
+    assert_eq!(rules.score(Hand::ROCK, Hand::PAPER), -1);
+    assert_eq!(rules.outcome_score(Outcome::Win), 1);
+    assert_eq!(rules.hand_score(Hand::PAPER), 0);
+}
+
+#[test]
+fn decoders_read_the_guide_like_the_parts() {
+    let rules = Rules::rock_paper_scissors();
+    let rounds = Day02::parse(EXAMPLE).unwrap();
+    assert_eq!(total_score(&rules, &rounds, &HandTable::default()), 15);
+    assert_eq!(total_score(&rules, &rounds, &OutcomeTable::default()), 12);
+
+    let always_win = OutcomeTable([Outcome::Win; 3]);
+    assert_eq!(total_score(&rules, &rounds, &always_win), 24);
+    let own = always_win.decode(&rules, Hand::ROCK, rounds[0].1);
+    assert_eq!(own, Hand::PAPER);
+}
+
+#[test]
+fn hand_tables_are_parsed_from_names() {
+    let rules = Rules::rock_paper_scissors();
+    let table = HandTable::parse(&rules, "X=scissors, Z=rock").unwrap();
+    assert_eq!(table, HandTable([Hand::SCISSORS, Hand::PAPER, Hand::ROCK]));
+    assert_eq!(table.describe(&rules), "X=scissors, Y=paper, Z=rock");
+    assert_eq!(
+        HandTable::parse(&rules, "W=rock").unwrap_err(),
+        "expected `X`, `Y` or `Z`, not `W`"
+    );
+    assert_eq!(
+        HandTable::parse(&rules, "X=lizard").unwrap_err(),
+        "unknown hand `lizard`"
+    );
+    assert!(HandTable::parse(&rules, "X").is_err());
+    assert_eq!(
+        HandTable::parse(&rules, "X=rock,Y=rock,Z=paper").unwrap_err(),
+        "hand `rock` is given for more than one letter"
+    );
+    // Letters left out keep their default hand
+    assert_eq!(
+        HandTable::parse(&rules, "X=paper").unwrap_err(),
+        "hand `paper` is given for more than one letter"
+    );
+}
+
+#[test]
+fn best_hand_table_of_all_permutations() {
+    let rules = Rules::rock_paper_scissors();
+    assert_eq!(HandTable::permutations(&rules).len(), 6);
+    assert_eq!(
+        HandTable::permutations(&Rules::rock_paper_scissors_lizard_spock()).len(),
+        60
+    );
+
+    let rounds = Day02::parse(EXAMPLE).unwrap();
+    let ranking = rank_hand_tables(&rules, &rounds);
+    assert_eq!(ranking.len(), 6);
+    assert_eq!(
+        ranking[0],
+        (HandTable([Hand::SCISSORS, Hand::PAPER, Hand::ROCK]), 24)
+    );
+    assert_eq!(ranking[5].1, 6);
+    assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+    let csv = ReportOptions {
+        format: ReportFormat::Csv,
+        ..Default::default()
+    };
+    let report = day02::report(EXAMPLE, &csv, None).unwrap();
+    assert!(
+        report.starts_with("X,Y,Z,score\nscissors,paper,rock,24\n"),
+        "{}",
+        report
+    );
+    let text = day02::report(EXAMPLE, &ReportOptions::default(), None).unwrap();
+    assert!(text.contains("Best reading: X=scissors, Y=paper, Z=rock (24)"));
+    assert!(text.contains("Read as lose, draw and win: 12"));
+
+    let table = HandTable::default();
+    let text = day02::report(EXAMPLE, &ReportOptions::default(), Some(&table)).unwrap();
+    assert!(text.ends_with("X=rock, Y=paper, Z=scissors scores 15, rank 2 of 6\n"));
+}
@@ -0,0 +1,79 @@
+//! Generating the files of a new day
+
+use advent_of_code_2022::scaffold::new_day;
+use std::fs;
+use std::path::PathBuf;
+
+/// Minimal crate layout with days 6 and 8 registered
+fn fixture(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for dir in ["src/days", "tests", "input/examples"] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    fs::write(
+        root.join("src/days/mod.rs"),
+        "pub mod day06;\npub mod day08;\n\npub const DAYS: &[Day] = &[\n    \
+         Day::of::<day06::Day06>(),\n    Day::of::<day08::Day08>(),\n];\n",
+    )
+    .unwrap();
+    fs::write(root.join("src/days/day06.rs"), "").unwrap();
+    fs::write(root.join("tests/days.rs"), "// tests\n").unwrap();
+    root
+}
+
+#[test]
+fn new_day_is_registered_in_order() {
+    let root = fixture("order");
+    let files = new_day(&root, 7, "No Space Left On Device").unwrap();
+    assert_eq!(files.len(), 4);
+
+    let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert_eq!(
+        registry,
+        "pub mod day06;\npub mod day07;\npub mod day08;\n\npub const DAYS: &[Day] = &[\n    \
+         Day::of::<day06::Day06>(),\n    Day::of::<day07::Day07>(),\n    \
+         Day::of::<day08::Day08>(),\n];\n"
+    );
+    let module = fs::read_to_string(root.join("src/days/day07.rs")).unwrap();
+    assert!(module.starts_with("//! Day 7: No Space Left On Device\n"));
+    assert!(module.contains("impl Solution for Day07"));
+    let tests = fs::read_to_string(root.join("tests/days.rs")).unwrap();
+    assert!(tests.contains("day07::Day07,\n    \"day7.txt\""));
+    assert!(root.join("input/examples/day7.txt").exists());
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn existing_day_is_left_alone() {
+    let root = fixture("existing");
+    let e = new_day(&root, 6, "Tuning Trouble").unwrap_err();
+    assert!(e.to_string().starts_with("day 6 already exists"));
+    assert!(new_day(&root, 26, "").is_err());
+    assert_eq!(
+        fs::read_to_string(root.join("tests/days.rs")).unwrap(),
+        "// tests\n"
+    );
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn fuzz_target_is_added_when_fuzzing_is_set_up() {
+    let root = fixture("fuzz");
+    fs::create_dir_all(root.join("fuzz/fuzz_targets")).unwrap();
+    fs::write(root.join("fuzz/Cargo.toml"), "[package]\n").unwrap();
+
+    let files = new_day(&root, 7, "No Space Left On Device").unwrap();
+    assert!(files.contains(&root.join("fuzz/fuzz_targets/day07.rs")));
+    let target = fs::read_to_string(root.join("fuzz/fuzz_targets/day07.rs")).unwrap();
+    assert!(target.contains("Day07::parse(input)"));
+    let manifest = fs::read_to_string(root.join("fuzz/Cargo.toml")).unwrap();
+    assert!(manifest.ends_with(
+        "[[bin]]\nname = \"day07\"\npath = \"fuzz_targets/day07.rs\"\n\
+         test = false\ndoc = false\nbench = false\n"
+    ));
+
+    fs::remove_dir_all(root).unwrap();
+}
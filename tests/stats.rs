@@ -0,0 +1,113 @@
+//! Statistics of `aoc report`
+
+use advent_of_code_2022::days::day01::{self, Elf};
+use advent_of_code_2022::stats::{percentile, Bucket, Histogram, ReportFormat, ReportOptions};
+use advent_of_code_2022::table;
+
+const EXAMPLE: &str = include_str!("../input/examples/day1.txt");
+
+#[test]
+fn percentiles_use_the_nearest_rank() {
+    let values = [15, 20, 35, 40, 50];
+    let at = |p| percentile(&values, p).unwrap();
+    assert_eq!(
+        [at(0), at(5), at(30), at(40), at(50), at(100)],
+        [15, 15, 20, 20, 35, 50]
+    );
+    assert_eq!(percentile::<u64>(&[], 50), None);
+}
+
+#[test]
+fn histogram_buckets_cover_the_range() {
+    let histogram = Histogram::new(&[1, 2, 2, 9, 10], 3);
+    assert_eq!(
+        histogram.buckets,
+        vec![
+            Bucket {
+                min: 1,
+                max: 4,
+                count: 3
+            },
+            Bucket {
+                min: 5,
+                max: 8,
+                count: 0
+            },
+            Bucket {
+                min: 9,
+                max: 10,
+                count: 2
+            },
+        ]
+    );
+    // Buckets are never narrower than one value
+    assert_eq!(Histogram::new(&[7, 7], 10).buckets.len(), 1);
+    assert_eq!(Histogram::new::<u64>(&[], 10), Histogram::default());
+}
+
+#[test]
+fn csv_cells_are_quoted_when_needed() {
+    let rows = vec![vec!["1,5".to_string(), "say \"hi\"".to_string()]];
+    assert_eq!(
+        table::csv(&["a", "b"], &rows),
+        "a,b\n\"1,5\",\"say \"\"hi\"\"\"\n"
+    );
+}
+
+#[test]
+fn inventory_keeps_the_items_of_every_elf() {
+    let elves = day01::parse_inventory::<i32>(EXAMPLE).unwrap();
+    assert_eq!(elves.len(), 5);
+    assert_eq!(elves[2].items, vec![5000, 6000]);
+    assert_eq!(
+        (elves[2].total(), elves[2].mean(), elves[2].max_item()),
+        (&11000, Some(5500.0), Some(&6000))
+    );
+    assert_eq!(Elf::<i32>::default().mean(), None);
+}
+
+#[test]
+fn day01_report_in_both_formats() {
+    let csv = ReportOptions {
+        format: ReportFormat::Csv,
+        ..Default::default()
+    };
+    assert_eq!(
+        day01::report("1\n2\n\n\n3\n", &csv).unwrap(),
+        "elf,items,total,mean,max_item\n1,2,3,1.5,2\n2,0,0,,\n3,1,3,3.0,3\n"
+    );
+
+    let text = day01::report(
+        EXAMPLE,
+        &ReportOptions {
+            buckets: 2,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert!(text.starts_with("elf  items  total  mean     max_item\n"));
+    assert!(
+        text.ends_with(
+            "5 elves carrying 10 items\n\
+         Totals: p0 4000, p25 6000, p50 10000, p75 11000, p90 24000, p99 24000, p100 24000\n\
+         \n\
+         \x204000 - 14000 | ######################################## 4\n\
+         14001 - 24000 | ########## 1\n"
+        ),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn day01_report_reads_totals_like_the_solver() {
+    let e = day01::report("1\n\n-5\n", &ReportOptions::default()).unwrap_err();
+    assert_eq!((e.line, e.column), (3, 1));
+    assert_eq!(e.expected, "calorie count");
+
+    let max = u64::MAX.to_string();
+    let report = day01::report(&format!("{}\n\n0\n", max), &ReportOptions::default()).unwrap();
+    assert!(report.contains(&format!("p100 {}", max)), "{}", report);
+    let histogram = Histogram::new(&[0, u64::MAX], 2);
+    assert_eq!(histogram.buckets[1].max, u64::MAX as i128);
+}
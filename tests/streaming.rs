@@ -0,0 +1,58 @@
+//! Streaming group reader and bounded top-N selection
+
+use advent_of_code_2022::days::day01;
+use advent_of_code_2022::parse::{lines, read_groups, split_groups, Group};
+use advent_of_code_2022::top::{top_n, TopN};
+
+#[test]
+fn groups_match_split_groups() {
+    for input in ["", "1\n", "1\r\n2\r\n\r\n3", "1\n\n\n2\n\n", "\n"] {
+        let streamed: Vec<Vec<String>> = read_groups(input.as_bytes())
+            .map(|group| group.unwrap().lines)
+            .collect();
+        let lines: Vec<&str> = input.lines().collect();
+        let split: Vec<Vec<String>> = split_groups(&lines)
+            .into_iter()
+            .map(|group| group.into_iter().map(String::from).collect())
+            .collect();
+        assert_eq!(streamed, split, "input {:?}", input);
+    }
+}
+
+#[test]
+fn group_lines_keep_their_numbers() {
+    let input = "1\n\n2\n3\n";
+    let groups: Vec<Group> = read_groups(input.as_bytes()).map(Result::unwrap).collect();
+    let numbers: Vec<usize> = groups[1].lines().map(|line| line.number).collect();
+    let expected: Vec<usize> = lines(input).skip(2).map(|line| line.number).collect();
+    assert_eq!(numbers, expected);
+}
+
+#[test]
+fn top_n_keeps_the_largest_values() {
+    assert_eq!(top_n([5, 1, 9, 3, 9, 7], 3), vec![9, 9, 7]);
+    assert_eq!(top_n([2, 1], 5), vec![2, 1]);
+    assert_eq!(top_n([2, 1], 0), Vec::<i32>::new());
+
+    let mut top = TopN::new(2);
+    top.extend([4, 8, 1]);
+    assert_eq!((top.len(), top.min()), (2, Some(&4)));
+}
+
+#[test]
+fn top_totals_of_the_example() {
+    let example = include_str!("../input/examples/day1.txt");
+    assert_eq!(
+        day01::top_totals::<i32, _>(example.as_bytes(), "day1.txt", 3).unwrap(),
+        vec![24000, 11000, 10000]
+    );
+}
+
+#[test]
+fn top_totals_errors_name_the_inventory() {
+    let e = day01::top_totals::<i32, _>("1\n\nx\n".as_bytes(), "dump.txt", 3).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "dump.txt:3:1: expected calorie count, found `x`"
+    );
+}
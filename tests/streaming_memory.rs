@@ -0,0 +1,63 @@
+//! Heap usage of the streaming day 1 reader
+//!
+//! The only test of its binary, so that no other test allocates while the
+//! usage is measured.
+
+use advent_of_code_2022::days::day01;
+use advent_of_code_2022::memory::{self, CountingAllocator};
+use std::io::{self, BufReader, Read};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Inventory of `elves` elves generated on the fly, with `i % 7 + 1` items
+/// of `i % 1000 + 1` calories for elf `i`
+struct Inventory {
+    elves: usize,
+    elf: usize,
+    pending: Vec<u8>,
+}
+
+impl Read for Inventory {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pending.is_empty() && self.elf < self.elves {
+            let i = self.elf;
+            for _ in 0..i % 7 + 1 {
+                self.pending
+                    .extend_from_slice(format!("{}\n", i % 1000 + 1).as_bytes());
+            }
+            if i + 1 < self.elves {
+                self.pending.push(b'\n');
+            }
+            self.elf += 1;
+        }
+        let n = buf.len().min(self.pending.len());
+        buf[..n].copy_from_slice(&self.pending[..n]);
+        self.pending.drain(..n);
+        Ok(n)
+    }
+}
+
+#[test]
+fn top_totals_are_read_in_constant_memory() {
+    let totals = |elves| {
+        let inventory = Inventory {
+            elves,
+            elf: 0,
+            pending: vec![],
+        };
+        memory::measure(|| day01::top_totals::<i32, _>(BufReader::new(inventory), "generated", 3))
+    };
+    let (top, small) = totals(1_000);
+    assert_eq!(top.unwrap(), vec![6958, 6909, 6860]);
+    let (top, large) = totals(100_000);
+    assert_eq!(top.unwrap(), vec![7000, 7000, 7000]);
+    // A hundred times more elves, about 2 MB of input, need no more memory
+    assert!(
+        large.peak < 2 * small.peak,
+        "{} vs {}",
+        large.peak,
+        small.peak
+    );
+    assert!(large.peak < 64 * 1024, "{}", large.peak);
+}
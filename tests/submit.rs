@@ -0,0 +1,160 @@
+//! Submitting answers against a local stand-in of the puzzle server
+
+mod common;
+
+use advent_of_code_2022::error::Error;
+use advent_of_code_2022::fetch::HttpFetcher;
+use advent_of_code_2022::solution::Part;
+use advent_of_code_2022::submit::{submit, Outcome, Refusal, SubmissionLog};
+use common::MockServer;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn temp_log(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-submit-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("submissions.log")
+}
+
+fn page(message: &str) -> String {
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        message
+    )
+}
+
+/// Server accepting 42 as the answer of day 1 part 1
+fn puzzle_server() -> MockServer {
+    MockServer::start(|request| {
+        let answer = request
+            .body
+            .split('&')
+            .find_map(|field| field.strip_prefix("answer="))
+            .and_then(|answer| answer.parse::<i64>().ok());
+        let message = match answer {
+            Some(42) => "That's the right answer! You are one gold star closer.",
+            Some(n) if n > 42 => "That's not the right answer; your answer is too high.",
+            Some(_) => "That's not the right answer; your answer is too low.",
+            None => "That's not the right answer.",
+        };
+        (200, page(message))
+    })
+}
+
+#[test]
+fn responses_are_classified() {
+    assert_eq!(
+        Outcome::from_response(&page("That's the right answer!")),
+        Outcome::Correct
+    );
+    assert_eq!(
+        Outcome::from_response(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Outcome::TooLow
+    );
+    assert_eq!(
+        Outcome::from_response(&page(
+            "You gave an answer too recently; you have to wait after submitting an \
+             answer before trying again.  You have 1m 5s left to wait."
+        )),
+        Outcome::RateLimited {
+            wait: Some(Duration::from_secs(65))
+        }
+    );
+    assert_eq!(
+        Outcome::from_response(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        )),
+        Outcome::AlreadySolved
+    );
+    assert_eq!(Outcome::from_response("<html></html>"), Outcome::Unknown);
+}
+
+#[test]
+fn attempts_are_posted_and_recorded() {
+    let server = puzzle_server();
+    let path = temp_log("record");
+    let client = HttpFetcher::new(&server.url, "secret");
+    let mut log = SubmissionLog::load(&path).unwrap();
+
+    let outcome = submit(&client, &mut log, 1, Part::One, "50", 1000).unwrap();
+    assert_eq!(outcome, Outcome::TooHigh);
+    let outcome = submit(&client, &mut log, 1, Part::One, "42", 1100).unwrap();
+    assert_eq!(outcome, Outcome::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=50");
+    assert_eq!(
+        requests[0].headers.get("cookie").map(String::as_str),
+        Some("session=secret")
+    );
+
+    // The log survives a reload
+    let reloaded = SubmissionLog::load(&path).unwrap();
+    assert_eq!(reloaded.attempts(), log.attempts());
+    assert_eq!(reloaded.attempts()[0].outcome, Outcome::TooHigh);
+    assert_eq!(reloaded.attempts()[1].answer, "42");
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn known_wrong_answers_and_cooldown_are_refused() {
+    let server = puzzle_server();
+    let path = temp_log("refuse");
+    let client = HttpFetcher::new(&server.url, "secret");
+    let mut log = SubmissionLog::load(&path).unwrap();
+
+    assert_eq!(
+        submit(&client, &mut log, 1, Part::One, "10", 1000).unwrap(),
+        Outcome::TooLow
+    );
+
+    let refusal = |result: Result<Outcome, Error>| match result {
+        Err(Error::Refused(refusal)) => refusal,
+        other => panic!("unexpected result {:?}", other),
+    };
+    assert_eq!(
+        refusal(submit(&client, &mut log, 1, Part::One, "10", 2000)),
+        Refusal::KnownWrong {
+            outcome: Outcome::TooLow
+        }
+    );
+    assert_eq!(
+        refusal(submit(&client, &mut log, 1, Part::One, "7", 2000)),
+        Refusal::OutOfBounds {
+            bound: "10".to_string(),
+            outcome: Outcome::TooLow
+        }
+    );
+    assert_eq!(
+        refusal(submit(&client, &mut log, 1, Part::One, "42", 1030)),
+        Refusal::Cooldown {
+            remaining: Duration::from_secs(30)
+        }
+    );
+    assert!(matches!(
+        refusal(submit(&client, &mut log, 1, Part::One, "##\n##", 2000)),
+        Refusal::Invalid { .. }
+    ));
+    assert_eq!(server.requests().len(), 1);
+
+    assert_eq!(
+        submit(&client, &mut log, 1, Part::One, "42", 1060).unwrap(),
+        Outcome::Correct
+    );
+    assert_eq!(
+        refusal(submit(&client, &mut log, 1, Part::One, "42", 2000)),
+        Refusal::AlreadySolved {
+            answer: "42".to_string()
+        }
+    );
+    assert_eq!(server.requests().len(), 2);
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
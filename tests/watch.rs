@@ -0,0 +1,77 @@
+//! Change detection and answer comparison of `aoc watch`
+
+use advent_of_code_2022::solution::Part;
+use advent_of_code_2022::watch::{compare, parse_answers, watched_paths, Change, Watcher};
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn watcher_reports_changed_and_created_files() {
+    let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let (a, b) = (dir.join("a.txt"), dir.join("b.txt"));
+    fs::write(&a, "1\n").unwrap();
+
+    let mut watcher = Watcher::new(vec![a.clone(), b.clone()]);
+    assert!(watcher.changed().is_empty());
+
+    // The size changes too, so coarse modification times do not matter
+    fs::write(&a, "12\n").unwrap();
+    assert_eq!(watcher.changed(), vec![a.clone()]);
+    assert!(watcher.changed().is_empty());
+
+    fs::write(&b, "").unwrap();
+    assert_eq!(watcher.changed(), vec![b]);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn module_input_and_examples_are_watched() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let paths = watched_paths(root, 9, Path::new("input/day9.txt"));
+    assert_eq!(
+        paths,
+        vec![
+            root.join("src/days/day09.rs"),
+            Path::new("input/day9.txt").to_path_buf(),
+            root.join("input/examples/day9-larger.txt"),
+            root.join("input/examples/day9.txt"),
+        ]
+    );
+}
+
+#[test]
+fn answers_are_parsed_from_text_output() {
+    let output = "Day 10 part 1: 13140\nDay 10 part 2:\n##..\n#..#\n";
+    assert_eq!(
+        parse_answers(output),
+        vec![
+            (Part::One, "13140".to_string()),
+            (Part::Two, "##..\n#..#\n".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn answers_are_compared_per_part() {
+    let previous = vec![(Part::One, "24000".to_string())];
+    let current = vec![
+        (Part::One, "24001".to_string()),
+        (Part::Two, "45000".to_string()),
+    ];
+    assert_eq!(
+        compare(&previous, &current),
+        vec![
+            Change::Changed {
+                previous: "24000".to_string()
+            },
+            Change::New,
+        ]
+    );
+    assert_eq!(
+        compare(&current, &current),
+        vec![Change::Unchanged, Change::Unchanged]
+    );
+}